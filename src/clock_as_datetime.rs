@@ -0,0 +1,144 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Serde helpers storing a [`SimulationClock`]'s time as a Metric Date Time string
+//!
+//! Use with `#[serde(with = "metriclock::clock_as_datetime")]` on a `SimulationClock` field to
+//! write Simulation Time in the human-editable `SimulationDateTime` form, such as
+//! `"12-03-04-05@06:07:08"`, instead of seconds. Every other field is written as usual.
+//! Sub-second precision is kept as a decimal fraction of the second, such as
+//! `"12-03-04-05@06:07:08.5"`. Loading accepts either form, so save files can move between the
+//! two representations.
+
+use crate::SimulationClock;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::Cell;
+
+thread_local! {
+    static DATETIME_FORM: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Serialize `clock` with its time written as a Metric Date Time string.
+pub fn serialize<S: Serializer>(clock: &SimulationClock, serializer: S) -> Result<S::Ok, S::Error> {
+    let _form = DateTimeForm::enter();
+    Serialize::serialize(clock, serializer)
+}
+
+/// Deserialize a clock whose time is either a Metric Date Time string or the default numeric form.
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SimulationClock, D::Error> {
+    <SimulationClock as Deserialize>::deserialize(deserializer)
+}
+
+/// Whether the clock being serialized on this thread should write its time as a Date Time.
+pub(crate) fn datetime_form() -> bool {
+    DATETIME_FORM.with(Cell::get)
+}
+
+/// Switches the Date Time form on for the current thread until dropped.
+struct DateTimeForm(bool);
+
+impl DateTimeForm {
+    fn enter() -> DateTimeForm {
+        DateTimeForm(DATETIME_FORM.with(|form| form.replace(true)))
+    }
+}
+
+impl Drop for DateTimeForm {
+    fn drop(&mut self) {
+        DATETIME_FORM.with(|form| form.set(self.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SimulationClock, SimulationTimestamp};
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+
+    #[derive(Serialize, Deserialize)]
+    struct Save {
+        #[serde(with = "crate::clock_as_datetime")]
+        clock: SimulationClock,
+    }
+
+    #[test]
+    fn datetime_round_trip_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(12, 3, 4, 5, 6, 7, 8);
+        clock.set_clock_speed(2.0);
+        let json = serde_json::to_string(&Save {
+            clock: clock.clone(),
+        })
+        .unwrap();
+        assert!(json.contains(r#""clock_time":"12-03-04-05@06:07:08""#));
+        let restored: Save = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.clock, clock);
+        assert_eq!(
+            restored.clock.atomic_time(),
+            SimulationTimestamp::from_components(12, 3, 4, 5, 6, 7, 8)
+        );
+
+        let numeric = serde_json::to_string(&clock).unwrap();
+        assert!(!numeric.contains("12-03-04-05@06:07:08"));
+        let edited: SimulationClock =
+            serde_json::from_str(r#"{"clock_time": "0-00-00-01@00:00:00"}"#).unwrap();
+        assert_eq!(
+            edited.current_timestamp(),
+            SimulationTimestamp::from_epoch_seconds(100_000)
+        );
+    }
+
+    #[test]
+    fn subsecond_round_trip_test() {
+        let mut clock = SimulationClock::default();
+        clock.tick(Duration::from_millis(1_500));
+        let json = serde_json::to_string(&Save {
+            clock: clock.clone(),
+        })
+        .unwrap();
+        assert!(json.contains(r#""clock_time":"0-00-00-00@00:00:01.5""#));
+        let restored: Save = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.clock, clock);
+        assert_eq!(
+            restored.clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::from_millis(1_500))
+        );
+    }
+
+    #[test]
+    fn exact_round_trip_test() {
+        let times = [
+            SimulationTimestamp::EPOCH,
+            SimulationTimestamp::from(Duration::new(123_456_789, 1)),
+            SimulationTimestamp::from_signed_epoch_seconds(-1) + Duration::from_millis(250),
+            SimulationTimestamp::from_signed_epoch_seconds(-260_000_000),
+            SimulationTimestamp::MIN,
+            SimulationTimestamp::MAX,
+        ];
+        for time in times {
+            let mut clock = SimulationClock::at(time);
+            clock.enable_turn_mode();
+            let json = serde_json::to_string(&Save {
+                clock: clock.clone(),
+            })
+            .unwrap();
+            let restored: Save = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.clock, clock, "{}", json);
+            assert_eq!(restored.clock.current_timestamp(), time);
+        }
+    }
+}
@@ -0,0 +1,533 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::{
+    MetricError, MetricScale, SECONDS_PER_METRIC_DAY, SECONDS_PER_METRIC_HOUR,
+    SECONDS_PER_METRIC_MINUTE, SECONDS_PER_METRIC_MONTH, SECONDS_PER_METRIC_WEEK,
+    SECONDS_PER_METRIC_YEAR,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Span of Simulation Time
+///
+/// Arithmetic saturates at `Duration::MAX` rather than panicking.
+///
+/// A Simulation Duration compares against a raw `Duration` by SI seconds, so one Metric Hour is
+/// equal to `Duration::from_secs(10_000)`, not to an SI hour.
+#[derive(
+    Copy, Clone, Debug, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash,
+)]
+pub struct SimulationDuration(Duration);
+
+/// Span of Simulation Time measured in Metric Units
+///
+/// This is the same type as [`SimulationDuration`], named for callers building spans from
+/// Metric Units rather than raw `Duration` seconds.
+pub type MetricDuration = SimulationDuration;
+
+impl SimulationDuration {
+    /// Create a new Simulation Duration spanning `duration`.
+    pub const fn new(duration: Duration) -> SimulationDuration {
+        SimulationDuration(duration)
+    }
+    /// Create a Simulation Duration spanning `count` of the `scale` Metric Unit, saturating at
+    /// `Duration::MAX`.
+    pub const fn from_units(count: u64, scale: MetricScale) -> SimulationDuration {
+        match count.checked_mul(scale.seconds_per_unit()) {
+            Some(seconds) => SimulationDuration(Duration::from_secs(seconds)),
+            None => SimulationDuration(Duration::MAX),
+        }
+    }
+    /// Create a Simulation Duration spanning `amount` of the `scale` Metric Unit.
+    ///
+    /// The nanosecond count is computed in `f64` and rounded to the nearest nanosecond, with
+    /// halves rounding up. It saturates at `Duration::MAX`, while a negative or NaN `amount`
+    /// gives a zero span. Use `from_units` for exact whole counts.
+    ///
+    /// The `from_metric_*` constructors forward here and accept any amount convertible to `f64`,
+    /// so both `from_metric_days(3)` and `from_metric_hours(2.5)` work. They are not `const`;
+    /// use `from_units` in const contexts and for `u64` counts.
+    pub fn from_units_f64(amount: f64, scale: MetricScale) -> SimulationDuration {
+        let nanos = (amount * scale.seconds_per_unit() as f64 * 1e9).round();
+        if nanos.is_nan() || nanos <= 0.0 {
+            return SimulationDuration::default();
+        }
+        SimulationDuration(crate::duration_from_nanos(nanos as u128).unwrap_or(Duration::MAX))
+    }
+    /// Create a Simulation Duration spanning `millennia` Metric Millennia, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_millennia(millennia: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(millennia.into(), MetricScale::Millennium)
+    }
+    /// Create a Simulation Duration spanning `centuries` Metric Centuries, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_centuries(centuries: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(centuries.into(), MetricScale::Century)
+    }
+    /// Create a Simulation Duration spanning `decades` Metric Decades, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_decades(decades: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(decades.into(), MetricScale::Decade)
+    }
+    /// Create a Simulation Duration spanning `years` Metric Years, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_years(years: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(years.into(), MetricScale::Year)
+    }
+    /// Create a Simulation Duration spanning `months` Metric Months, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_months(months: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(months.into(), MetricScale::Month)
+    }
+    /// Create a Simulation Duration spanning `weeks` Metric Weeks, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_weeks(weeks: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(weeks.into(), MetricScale::Week)
+    }
+    /// Create a Simulation Duration spanning `days` Metric Days, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_days(days: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(days.into(), MetricScale::Day)
+    }
+    /// Create a Simulation Duration spanning `hours` Metric Hours, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_hours(hours: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(hours.into(), MetricScale::Hour)
+    }
+    /// Create a Simulation Duration spanning `minutes` Metric Minutes, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_minutes(minutes: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(minutes.into(), MetricScale::Minute)
+    }
+    /// Create a Simulation Duration spanning `seconds` Metric Seconds, rounded as
+    /// `from_units_f64`.
+    pub fn from_metric_seconds(seconds: impl Into<f64>) -> SimulationDuration {
+        SimulationDuration::from_units_f64(seconds.into(), MetricScale::Second)
+    }
+    /// Span of this Simulation Duration as a raw `Duration`.
+    pub const fn as_duration(&self) -> Duration {
+        self.0
+    }
+    /// Span of this Simulation Duration in SI seconds, the unit used when comparing against a
+    /// raw `Duration`.
+    pub fn as_si(&self) -> Duration {
+        self.0
+    }
+    /// Span of this Simulation Duration expressed in `scale` units.
+    pub fn as_unit(&self, scale: MetricScale) -> f64 {
+        self.0.as_secs_f64() / scale.seconds_per_unit() as f64
+    }
+    /// Number of whole `scale` units in this Simulation Duration, rounded down.
+    pub fn whole_units(&self, scale: MetricScale) -> u64 {
+        self.0.as_secs() / scale.seconds_per_unit()
+    }
+    /// Break this span down into whole Metric Units from years to seconds.
+    pub fn components(&self) -> MetricElapsed {
+        let seconds = self.0.as_secs();
+        let unit = |per_unit: u64, per_parent: u64| (seconds % per_parent / per_unit) as u8;
+        MetricElapsed {
+            years: seconds / SECONDS_PER_METRIC_YEAR,
+            months: unit(SECONDS_PER_METRIC_MONTH, SECONDS_PER_METRIC_YEAR),
+            weeks: unit(SECONDS_PER_METRIC_WEEK, SECONDS_PER_METRIC_MONTH),
+            days: unit(SECONDS_PER_METRIC_DAY, SECONDS_PER_METRIC_WEEK),
+            hours: unit(SECONDS_PER_METRIC_HOUR, SECONDS_PER_METRIC_DAY),
+            minutes: unit(SECONDS_PER_METRIC_MINUTE, SECONDS_PER_METRIC_HOUR),
+            seconds: unit(1, SECONDS_PER_METRIC_MINUTE),
+            nanoseconds: self.0.subsec_nanos(),
+        }
+    }
+    /// Parse a span such as `"1 metric hour 30 metric minutes"` or `"6 mm"`.
+    ///
+    /// Input is a sequence of amount and unit pairs whose spans are summed. Units may be spelled
+    /// out, singular or plural, with an optional `metric` prefix, or abbreviated as `my` (year),
+    /// `mmo` (month), `mw` (week), `md` (day), `mh` (hour), `mm` (minute), or `ms` (second).
+    ///
+    /// Malformed input, including negative or NaN amounts, fails with `MetricError::Parse`. A
+    /// span too large for a `Duration`, whether from one term or the sum, fails with
+    /// `MetricError::Overflow`.
+    pub fn parse(input: &str) -> Result<SimulationDuration, MetricError> {
+        let parse_error = || MetricError::Parse(input.to_string());
+        let mut tokens = input
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty());
+        let mut total = Duration::ZERO;
+        let mut empty = true;
+        while let Some(amount) = tokens.next() {
+            let amount = amount.parse::<f64>().map_err(|_| parse_error())?;
+            if amount.is_nan() || amount < 0.0 {
+                return Err(parse_error());
+            }
+            let mut unit = tokens.next().ok_or_else(parse_error)?;
+            if unit.eq_ignore_ascii_case("metric") {
+                unit = tokens.next().ok_or_else(parse_error)?;
+            }
+            let scale = parse_unit(unit).ok_or_else(parse_error)?;
+            let span = Duration::try_from_secs_f64(amount * scale.seconds_per_unit() as f64)
+                .map_err(|_| MetricError::Overflow)?;
+            total = total.checked_add(span).ok_or(MetricError::Overflow)?;
+            empty = false;
+        }
+        if empty {
+            return Err(parse_error());
+        }
+        Ok(SimulationDuration(total))
+    }
+}
+
+fn parse_unit(unit: &str) -> Option<MetricScale> {
+    let scale = match unit.to_ascii_lowercase().as_str() {
+        "millennium" | "millennia" | "millenniums" => MetricScale::Millennium,
+        "century" | "centuries" => MetricScale::Century,
+        "decade" | "decades" => MetricScale::Decade,
+        "year" | "years" | "my" => MetricScale::Year,
+        "month" | "months" | "mmo" => MetricScale::Month,
+        "week" | "weeks" | "mw" => MetricScale::Week,
+        "day" | "days" | "md" => MetricScale::Day,
+        "hour" | "hours" | "mh" => MetricScale::Hour,
+        "minute" | "minutes" | "mm" => MetricScale::Minute,
+        "second" | "seconds" | "ms" => MetricScale::Second,
+        _ => return None,
+    };
+    Some(scale)
+}
+
+impl From<Duration> for SimulationDuration {
+    fn from(duration: Duration) -> Self {
+        SimulationDuration(duration)
+    }
+}
+
+impl From<SimulationDuration> for Duration {
+    fn from(duration: SimulationDuration) -> Self {
+        duration.0
+    }
+}
+
+impl std::ops::Add for SimulationDuration {
+    type Output = SimulationDuration;
+    fn add(self, rhs: SimulationDuration) -> SimulationDuration {
+        SimulationDuration(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::AddAssign for SimulationDuration {
+    fn add_assign(&mut self, rhs: SimulationDuration) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::iter::Sum for SimulationDuration {
+    fn sum<I: Iterator<Item = SimulationDuration>>(iter: I) -> SimulationDuration {
+        iter.fold(SimulationDuration::default(), |total, duration| {
+            total + duration
+        })
+    }
+}
+
+impl<'a> std::iter::Sum<&'a SimulationDuration> for SimulationDuration {
+    fn sum<I: Iterator<Item = &'a SimulationDuration>>(iter: I) -> SimulationDuration {
+        iter.copied().sum()
+    }
+}
+
+impl PartialEq<Duration> for SimulationDuration {
+    fn eq(&self, other: &Duration) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<Duration> for SimulationDuration {
+    fn partial_cmp(&self, other: &Duration) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/// Span of Simulation Time broken down into Metric Units, as returned by
+/// [`SimulationDuration::components`]
+///
+/// Every Metric Unit holds a fixed number of seconds, so the breakdown of an elapsed span does
+/// not depend on where it starts. Each field other than `years` is below the size of the next
+/// larger unit.
+#[derive(
+    Copy, Clone, Debug, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash,
+)]
+pub struct MetricElapsed {
+    pub years: u64,
+    pub months: u8,
+    pub weeks: u8,
+    pub days: u8,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+    pub nanoseconds: u32,
+}
+
+/// Span of Simulation Time that may point backwards
+///
+/// A zero span is never negative, so `SignedDuration::negative(Duration::ZERO)` equals
+/// `SignedDuration::default()`.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[serde(from = "SignedDurationFields")]
+pub struct SignedDuration {
+    magnitude: Duration,
+    negative: bool,
+}
+
+/// Serialized form of a Signed Duration, which may carry a negative zero
+#[derive(Deserialize)]
+struct SignedDurationFields {
+    magnitude: Duration,
+    negative: bool,
+}
+
+impl From<SignedDurationFields> for SignedDuration {
+    fn from(fields: SignedDurationFields) -> Self {
+        if fields.negative {
+            SignedDuration::negative(fields.magnitude)
+        } else {
+            SignedDuration::positive(fields.magnitude)
+        }
+    }
+}
+
+impl SignedDuration {
+    /// Create a Signed Duration pointing forwards by `magnitude`.
+    pub fn positive(magnitude: Duration) -> SignedDuration {
+        SignedDuration {
+            magnitude,
+            negative: false,
+        }
+    }
+    /// Create a Signed Duration pointing backwards by `magnitude`.
+    pub fn negative(magnitude: Duration) -> SignedDuration {
+        SignedDuration {
+            magnitude,
+            negative: !magnitude.is_zero(),
+        }
+    }
+    /// Unsigned length of this span.
+    pub fn magnitude(&self) -> Duration {
+        self.magnitude
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    /// Shift `duration` by this span, saturating at zero and `Duration::MAX`.
+    pub fn apply_to(&self, duration: Duration) -> Duration {
+        if self.negative {
+            duration.saturating_sub(self.magnitude)
+        } else {
+            duration.saturating_add(self.magnitude)
+        }
+    }
+}
+
+impl Ord for SignedDuration {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (negative, _) => other.negative.cmp(&negative),
+        }
+    }
+}
+
+impl PartialOrd for SignedDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MetricDuration, MetricElapsed, SignedDuration, SimulationDuration};
+    use crate::{MetricError, MetricScale};
+    use std::time::Duration;
+
+    #[test]
+    fn sum_test() {
+        let durations = vec![
+            SimulationDuration::new(Duration::from_secs(100)),
+            SimulationDuration::new(Duration::from_secs(10_000)),
+            SimulationDuration::new(Duration::from_millis(500)),
+        ];
+        let total: SimulationDuration = durations.iter().sum();
+        assert_eq!(total.as_duration(), Duration::from_millis(10_100_500));
+        assert_eq!(durations.into_iter().sum::<SimulationDuration>(), total);
+
+        let empty: Vec<SimulationDuration> = Vec::new();
+        assert_eq!(
+            empty.into_iter().sum::<SimulationDuration>(),
+            SimulationDuration::default()
+        );
+
+        let saturated: SimulationDuration = [Duration::MAX, Duration::from_secs(1)]
+            .into_iter()
+            .map(SimulationDuration::from)
+            .sum();
+        assert_eq!(saturated.as_duration(), Duration::MAX);
+    }
+
+    #[test]
+    fn unit_constructors_test() {
+        let days = MetricDuration::from_metric_days(3);
+        assert_eq!(days.as_duration(), Duration::from_secs(300_000));
+        assert_eq!(days.whole_units(MetricScale::Day), 3);
+        assert_eq!(days.whole_units(MetricScale::Week), 0);
+        assert_eq!(days.as_unit(MetricScale::Week), 0.3);
+        assert_eq!(days.whole_units(MetricScale::Minute), 3_000);
+
+        let span = MetricDuration::from_metric_hours(2.5);
+        assert_eq!(span.as_duration(), Duration::from_secs(25_000));
+        assert_eq!(span.as_unit(MetricScale::Hour), 2.5);
+        assert_eq!(span, SimulationDuration::parse("2.5 mh").unwrap());
+
+        for &scale in MetricScale::all() {
+            assert_eq!(
+                MetricDuration::from_units(7, scale).whole_units(scale),
+                7,
+                "{:?}",
+                scale
+            );
+        }
+        assert_eq!(
+            MetricDuration::from_units(u64::MAX, MetricScale::Millennium).as_duration(),
+            Duration::MAX
+        );
+        assert_eq!(
+            MetricDuration::from_metric_millennia(1e12).as_duration(),
+            Duration::MAX
+        );
+        assert_eq!(
+            MetricDuration::from_metric_seconds(1.0000000016).as_duration(),
+            Duration::new(1, 2)
+        );
+        assert_eq!(
+            MetricDuration::from_metric_days(-1.0),
+            MetricDuration::default()
+        );
+        assert_eq!(
+            MetricDuration::from_metric_days(f64::NAN),
+            MetricDuration::default()
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        let single = SimulationDuration::parse("6 metric minutes").unwrap();
+        assert_eq!(single.as_duration(), Duration::from_secs(600));
+
+        let multi = SimulationDuration::parse("1 metric hour 30 metric minutes").unwrap();
+        assert_eq!(multi.as_duration(), Duration::from_secs(13_000));
+
+        let abbreviated = SimulationDuration::parse("2 md, 1.5 mh 3 ms").unwrap();
+        assert_eq!(abbreviated.as_duration(), Duration::from_secs(215_003));
+
+        for malformed in [
+            "",
+            "6",
+            "metric hour",
+            "6 metric",
+            "6 fortnights",
+            "-1 mh",
+            "nan mh",
+            "1 mh 2",
+            "1e400 fortnights",
+        ] {
+            assert!(
+                matches!(
+                    SimulationDuration::parse(malformed),
+                    Err(MetricError::Parse(_))
+                ),
+                "{:?}",
+                malformed
+            );
+        }
+        for overflowing in [
+            "1e400 mh",
+            "1e12 millennia",
+            "100000000 millennia 100000000 millennia",
+        ] {
+            assert_eq!(
+                SimulationDuration::parse(overflowing),
+                Err(MetricError::Overflow),
+                "{:?}",
+                overflowing
+            );
+        }
+    }
+
+    #[test]
+    fn signed_duration_test() {
+        let back = SignedDuration::negative(Duration::from_secs(5));
+        let forward = SignedDuration::positive(Duration::from_secs(5));
+        assert!(back.is_negative());
+        assert!(back < SignedDuration::default());
+        assert!(SignedDuration::default() < forward);
+        assert!(SignedDuration::negative(Duration::from_secs(9)) < back);
+        assert_eq!(
+            SignedDuration::negative(Duration::ZERO),
+            SignedDuration::default()
+        );
+        assert_eq!(back.apply_to(Duration::from_secs(3)), Duration::ZERO);
+        assert_eq!(
+            forward.apply_to(Duration::from_secs(3)),
+            Duration::from_secs(8)
+        );
+
+        let negative_zero: SignedDuration =
+            serde_json::from_str(r#"{"magnitude":{"secs":0,"nanos":0},"negative":true}"#).unwrap();
+        assert_eq!(negative_zero, SignedDuration::default());
+        assert!(!negative_zero.is_negative());
+        let json = serde_json::to_string(&back).unwrap();
+        assert_eq!(serde_json::from_str::<SignedDuration>(&json).unwrap(), back);
+    }
+
+    #[test]
+    fn si_comparison_test() {
+        let hour = SimulationDuration::parse("1 metric hour").unwrap();
+        assert_eq!(hour.as_si(), Duration::from_secs(10_000));
+        assert!(hour == Duration::from_secs(10_000));
+        assert!(hour < Duration::from_secs(3_600 * 3));
+        assert!(hour > Duration::from_secs(3_600));
+    }
+
+    #[test]
+    fn components_test() {
+        let span = MetricDuration::from_metric_years(42)
+            + MetricDuration::from_metric_weeks(13)
+            + MetricDuration::from_metric_minutes(150)
+            + SimulationDuration::new(Duration::from_millis(7_250));
+        assert_eq!(
+            span.components(),
+            MetricElapsed {
+                years: 42,
+                months: 1,
+                weeks: 3,
+                days: 0,
+                hours: 1,
+                minutes: 50,
+                seconds: 7,
+                nanoseconds: 250_000_000,
+            }
+        );
+        assert_eq!(
+            SimulationDuration::default().components(),
+            MetricElapsed::default()
+        );
+    }
+}
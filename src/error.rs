@@ -0,0 +1,52 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+/// Metric Time Error
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetricError {
+    /// Arithmetic exceeded the representable range of Simulation Time
+    Overflow,
+    /// A Date Time component was outside of its valid range
+    OutOfRange {
+        /// Name of the offending component
+        component: &'static str,
+        /// Value that was provided
+        value: u64,
+        /// Largest valid value
+        max: u64,
+    },
+    /// Input could not be parsed
+    Parse(String),
+    /// Clock Speed was negative, NaN, or infinite
+    InvalidSpeed(f64),
+}
+
+impl std::fmt::Display for MetricError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetricError::Overflow => write!(f, "simulation time overflowed"),
+            MetricError::OutOfRange {
+                component,
+                value,
+                max,
+            } => write!(f, "{} {} is out of range 0..={}", component, value, max),
+            MetricError::Parse(input) => write!(f, "unable to parse {:?}", input),
+            MetricError::InvalidSpeed(speed) => write!(f, "invalid clock speed {}", speed),
+        }
+    }
+}
+
+impl std::error::Error for MetricError {}
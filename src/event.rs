@@ -0,0 +1,104 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::{ClockSource, SimulationTimestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Typed events keyed by Simulation Time
+///
+/// Events are drained in time order once a clock reaches them. Events sharing a time are drained
+/// in the order they were pushed.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EventQueue<T> {
+    /// Pending events in chronological order
+    events: VecDeque<(SimulationTimestamp, T)>,
+}
+
+impl<T> EventQueue<T> {
+    /// Create an empty Event Queue.
+    pub fn new() -> EventQueue<T> {
+        EventQueue {
+            events: VecDeque::new(),
+        }
+    }
+    /// Queue `payload` to become due once Simulation Time reaches `at`.
+    pub fn push(&mut self, at: SimulationTimestamp, payload: T) {
+        let index = self.events.partition_point(|(time, _)| *time <= at);
+        self.events.insert(index, (at, payload));
+    }
+    /// Remove and return, in time order, every event due at the time reported by `clock`.
+    pub fn drain_due(&mut self, clock: &impl ClockSource) -> Vec<T> {
+        self.drain_until(clock.now())
+    }
+    /// Remove and return, in time order, every event due at `now`.
+    pub(crate) fn drain_until(&mut self, now: SimulationTimestamp) -> Vec<T> {
+        let due = self.events.partition_point(|(time, _)| *time <= now);
+        self.events
+            .drain(..due)
+            .map(|(_, payload)| payload)
+            .collect()
+    }
+    /// Time of the earliest pending event.
+    pub fn next_due(&self) -> Option<&SimulationTimestamp> {
+        self.events.front().map(|(time, _)| time)
+    }
+    /// Number of events not yet drained.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+    /// Whether every event has been drained.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Opaque handle to an event scheduled on a [`SimulationClock`](crate::SimulationClock)
+///
+/// Handles are issued in increasing order by `SimulationClock::schedule_event` and are never
+/// reused by the same clock.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct EventHandle(pub(crate) u64);
+
+impl<T> Default for EventQueue<T> {
+    fn default() -> Self {
+        EventQueue::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventQueue;
+    use crate::{MockClockSource, SimulationTimestamp};
+
+    #[test]
+    fn drain_due_test() {
+        let mut queue = EventQueue::new();
+        queue.push(SimulationTimestamp::from_epoch_seconds(30), "harvest");
+        queue.push(SimulationTimestamp::from_epoch_seconds(10), "plant");
+        queue.push(SimulationTimestamp::from_epoch_seconds(50), "winter");
+        queue.push(SimulationTimestamp::from_epoch_seconds(10), "water");
+        let mut clock = MockClockSource::new(SimulationTimestamp::from_epoch_seconds(5));
+        assert!(queue.drain_due(&clock).is_empty());
+        clock.set_now(SimulationTimestamp::from_epoch_seconds(30));
+        assert_eq!(queue.drain_due(&clock), vec!["plant", "water", "harvest"]);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(
+            queue.next_due(),
+            Some(&SimulationTimestamp::from_epoch_seconds(50))
+        );
+    }
+}
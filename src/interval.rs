@@ -0,0 +1,135 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::{SimulationDuration, SimulationTimestamp};
+use serde::{Deserialize, Serialize};
+
+/// Half-open span of Simulation Time from `start` up to but excluding `end`
+///
+/// An interval whose `end` is not after its `start` is empty, containing no timestamps.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct MetricInterval {
+    start: SimulationTimestamp,
+    end: SimulationTimestamp,
+}
+
+impl MetricInterval {
+    /// Create an interval from `start` up to `end`, empty if `end` is not after `start`.
+    pub fn new(start: SimulationTimestamp, end: SimulationTimestamp) -> MetricInterval {
+        MetricInterval {
+            start,
+            end: end.max(start),
+        }
+    }
+    /// Create an interval of `duration` starting at `start`, saturating at the latest
+    /// representable timestamp.
+    pub fn starting_at(start: SimulationTimestamp, duration: SimulationDuration) -> MetricInterval {
+        MetricInterval::new(start, start + duration)
+    }
+    pub fn start(&self) -> SimulationTimestamp {
+        self.start
+    }
+    pub fn end(&self) -> SimulationTimestamp {
+        self.end
+    }
+    /// Span from `start` to `end`.
+    pub fn duration(&self) -> SimulationDuration {
+        self.end.duration_since(&self.start)
+    }
+    /// Whether this interval contains no timestamps.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+    /// Whether `timestamp` is at or after `start` and before `end`.
+    pub fn contains(&self, timestamp: SimulationTimestamp) -> bool {
+        self.start <= timestamp && timestamp < self.end
+    }
+    /// Whether the two intervals share at least one timestamp.
+    ///
+    /// Intervals that only touch, where one ends as the other starts, do not overlap, and an
+    /// empty interval overlaps nothing.
+    pub fn overlaps(&self, other: &MetricInterval) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+    /// Timestamps in both intervals, or `None` when they do not overlap.
+    pub fn intersection(&self, other: &MetricInterval) -> Option<MetricInterval> {
+        self.overlaps(other)
+            .then(|| MetricInterval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+    /// Single interval covering both, or `None` when a gap separates them.
+    ///
+    /// Intervals that touch are joined, and an empty interval joins nothing onto the other.
+    pub fn union(&self, other: &MetricInterval) -> Option<MetricInterval> {
+        if self.is_empty() {
+            return Some(*other);
+        }
+        if other.is_empty() {
+            return Some(*self);
+        }
+        (self.start <= other.end && other.start <= self.end)
+            .then(|| MetricInterval::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MetricInterval;
+    use crate::{SimulationDuration, SimulationTimestamp};
+
+    fn interval(start: i64, end: i64) -> MetricInterval {
+        MetricInterval::new(
+            SimulationTimestamp::from_signed_epoch_seconds(start),
+            SimulationTimestamp::from_signed_epoch_seconds(end),
+        )
+    }
+
+    #[test]
+    fn interval_set_operations_test() {
+        let morning = interval(0, 50_000);
+        let midday = interval(30_000, 70_000);
+        let evening = interval(50_000, 100_000);
+        let night = interval(-20_000, -10_000);
+
+        assert!(morning.contains(SimulationTimestamp::from_epoch_seconds(0)));
+        assert!(!morning.contains(SimulationTimestamp::from_epoch_seconds(50_000)));
+        assert_eq!(morning.duration(), SimulationDuration::from_metric_hours(5));
+
+        assert!(morning.overlaps(&midday));
+        assert!(!morning.overlaps(&evening));
+        assert_eq!(
+            morning.intersection(&midday),
+            Some(interval(30_000, 50_000))
+        );
+        assert_eq!(morning.intersection(&evening), None);
+
+        assert_eq!(morning.union(&evening), Some(interval(0, 100_000)));
+        assert_eq!(midday.union(&morning), Some(interval(0, 70_000)));
+        assert_eq!(morning.union(&night), None);
+        assert_eq!(night.union(&interval(500, 500)), Some(night));
+
+        let reversed = interval(10, 5);
+        assert!(reversed.is_empty());
+        assert_eq!(reversed.duration(), SimulationDuration::default());
+        assert!(!reversed.overlaps(&interval(0, 20)));
+        assert_eq!(
+            MetricInterval::starting_at(
+                SimulationTimestamp::from_epoch_seconds(30_000),
+                SimulationDuration::from_metric_hours(4)
+            ),
+            midday
+        );
+    }
+}
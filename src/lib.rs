@@ -32,9 +32,57 @@
 //! | 1 Metric Minute    | 100 Metric Seconds  | 0.000001   | 100 Seconds             | 1 Min 40 Sec                              |
 //! | 1 Metric Second    |                     | 0.00000001 | 1 Second                | 1 Second                                  |
 //!
+//! ## Features
+//! * `transient-turn-state` - Do not persist the time remaining in the current Turn. A clock
+//!   loaded in Turn mode restarts its Turn from the full Turn Duration, trading the exact
+//!   mid-turn position for a clean Turn boundary that game logic can resynchronize against.
+//! * `rng` - Add `SimulationClock::from_rng` for seeding clocks at random times in fuzz and
+//!   property tests.
+//!
+
+pub mod clock_as_datetime;
+mod duration;
+mod error;
+mod event;
+mod interval;
+mod rollover;
+mod scale;
+mod source;
+mod timeline;
+
+pub use crate::duration::{MetricDuration, MetricElapsed, SignedDuration, SimulationDuration};
+pub use crate::error::MetricError;
+pub use crate::event::{EventHandle, EventQueue};
+pub use crate::interval::MetricInterval;
+use crate::rollover::RolloverCallbacks;
+pub use crate::rollover::RolloverFlags;
+pub use crate::scale::{MetricScale, MetricUnit};
+pub use crate::source::{ClockReader, ClockSource, MockClockSource};
+pub use crate::timeline::Timeline;
+
+use crate::source::AtomicEpoch;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
-use serde::{Deserialize, Serialize};
-use std::time::Duration;
+/// Seconds in a Metric Millennium
+pub const SECONDS_PER_METRIC_MILLENNIUM: u64 = 100_000_000_000;
+/// Seconds in a Metric Century
+pub const SECONDS_PER_METRIC_CENTURY: u64 = 10_000_000_000;
+/// Seconds in a Metric Decade
+pub const SECONDS_PER_METRIC_DECADE: u64 = 1_000_000_000;
+/// Seconds in a Metric Year
+pub const SECONDS_PER_METRIC_YEAR: u64 = 100_000_000;
+/// Seconds in a Metric Month
+pub const SECONDS_PER_METRIC_MONTH: u64 = 10_000_000;
+/// Seconds in a Metric Week
+pub const SECONDS_PER_METRIC_WEEK: u64 = 1_000_000;
+/// Seconds in a Metric Day
+pub const SECONDS_PER_METRIC_DAY: u64 = 100_000;
+/// Seconds in a Metric Hour
+pub const SECONDS_PER_METRIC_HOUR: u64 = 10_000;
+/// Seconds in a Metric Minute
+pub const SECONDS_PER_METRIC_MINUTE: u64 = 100;
 
 /// Simulation Clock
 ///
@@ -47,20 +95,94 @@ use std::time::Duration;
 ///clock.tick(Duration::from_micros(50));
 /// ```
 ///
+/// Fields missing from serialized data take their `Default` values, and Simulation Time may be
+/// given as whole `epoch_seconds`, so a minimal config such as `{"epoch_seconds": 500}` loads as
+/// a Real-Time clock at speed 1.0.
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", default)]
 pub struct SimulationClock {
     /// Seconds since Simulation Epoch
-    clock_time: Duration,
+    #[serde(
+        alias = "epoch_seconds",
+        serialize_with = "serialize_clock_time",
+        deserialize_with = "deserialize_clock_time"
+    )]
+    clock_time: SimulationTimestamp,
     /// Current Clock Mode
     /// * TurnBased
+    /// * StrictTurn
     /// * RealTime
     clock_mode: ClockMode,
     /// Clock Speed Multiplier
     clock_speed: f64,
+    /// Slowest Clock Speed accepted by `set_clock_speed`
+    #[serde(default)]
+    clock_speed_min: f64,
+    /// Fastest Clock Speed accepted by `set_clock_speed`
+    #[serde(default = "default_clock_speed_max")]
+    clock_speed_max: f64,
+    /// Exact `(numerator, denominator)` Clock Speed set by `set_clock_speed_ratio`
+    #[serde(default)]
+    clock_speed_ratio: Option<(u32, u32)>,
     /// Duration of a Turn
     turn_duration: Duration,
     /// Duration remaining in this Turn
+    #[cfg_attr(feature = "transient-turn-state", serde(skip_serializing, default))]
     turn_time_remaining: Duration,
+    /// Real Time ticked since the current Turn completed without being advanced
+    #[serde(default)]
+    turn_stall_time: Duration,
+    /// Real Time a completed Turn must wait before it is reported complete
+    #[serde(default)]
+    turn_grace: Duration,
+    /// Time remaining below which a Turn is treated as complete
+    #[serde(default = "default_turn_epsilon")]
+    turn_epsilon: Duration,
+    /// Number of Turns advanced since Turn mode was first enabled
+    #[serde(default)]
+    turn_number: u64,
+    /// Simulation Time at which the current Turn began
+    #[serde(default = "default_turn_start")]
+    turn_start: SimulationTimestamp,
+    /// Number of evenly spaced phases each Turn is divided into
+    #[serde(default = "default_turn_subdivisions")]
+    turn_subdivisions: u32,
+    /// Whether `tick` asserts that Simulation Time never decreases in debug builds
+    #[serde(default)]
+    monotonic: bool,
+    /// Period after which the displayed calendar wraps back to the epoch
+    #[serde(default)]
+    calendar_cycle: Option<Duration>,
+    /// Offset of the local Date Time from Simulation Time
+    #[serde(default)]
+    local_offset: SignedDuration,
+    /// Real Time passed to `tick` during this session, before the speed multiplier
+    #[serde(skip)]
+    session_real_time: Duration,
+    /// Scripted speed changes applied as the clock reaches them
+    #[serde(default)]
+    timeline: Option<Timeline>,
+    /// Callbacks fired by `tick` when a Metric Unit rolls over
+    #[serde(skip)]
+    rollover_callbacks: RolloverCallbacks,
+    /// Whether the most recent `tick` ran the current Turn out
+    #[serde(skip)]
+    turn_just_completed: bool,
+    /// Number of recent ticks averaged by `average_speed`
+    #[serde(default)]
+    speed_sample_window: usize,
+    /// `(real, simulated)` seconds of the most recent ticks, oldest first
+    #[serde(skip)]
+    speed_samples: VecDeque<(f64, f64)>,
+    /// Whole epoch seconds published for lock-free readers
+    #[serde(skip)]
+    atomic_epoch: AtomicEpoch,
+    /// Events scheduled with `schedule_event` and not yet reported by `tick_detailed`
+    #[serde(default)]
+    scheduled_events: EventQueue<EventHandle>,
+    /// Handle issued to the next scheduled event
+    #[serde(default)]
+    next_event_handle: u64,
 }
 
 /// Clock Mode
@@ -70,20 +192,166 @@ pub enum ClockMode {
     TurnBased,
     /// Real-Time Clock
     RealTime,
+    /// Turn-Based Clock where `tick` is inert and only `advance_turn` moves time, by exactly one
+    /// Turn Duration
+    StrictTurn,
+}
+
+/// Starting settings for a [`SimulationClock`] that can be built in `const` and `static` items
+///
+/// ```rust
+/// use metriclock::{ClockMode, SimulationClock, SimulationClockConfig, SimulationTimestamp};
+/// use metriclock::{MetricScale, SimulationDuration};
+///
+/// static CAMPAIGN: SimulationClockConfig =
+///     SimulationClockConfig::new(SimulationTimestamp::from_epoch_seconds(1_200_000_000))
+///         .with_mode(ClockMode::TurnBased)
+///         .with_turn_duration(SimulationDuration::from_units(1, MetricScale::Day).as_duration());
+///
+/// let clock = SimulationClock::from_config(&CAMPAIGN);
+/// assert!(clock.is_turn_based());
+/// assert_eq!(clock.current_timestamp().year(), 12);
+/// ```
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SimulationClockConfig {
+    /// Simulation Time the clock starts at
+    pub start: SimulationTimestamp,
+    /// Clock Mode the clock starts in
+    pub mode: ClockMode,
+    /// Clock Speed Multiplier
+    pub speed: f64,
+    /// Duration of a Turn
+    pub turn_duration: Duration,
+}
+
+impl SimulationClockConfig {
+    /// Settings for a Real-Time clock at `start` running at speed 1.0 with 6 second Turns, as
+    /// `SimulationClock::default` uses.
+    pub const fn new(start: SimulationTimestamp) -> SimulationClockConfig {
+        SimulationClockConfig {
+            start,
+            mode: ClockMode::RealTime,
+            speed: 1.0,
+            turn_duration: Duration::from_secs(6),
+        }
+    }
+    pub const fn with_mode(self, mode: ClockMode) -> SimulationClockConfig {
+        SimulationClockConfig { mode, ..self }
+    }
+    pub const fn with_speed(self, speed: f64) -> SimulationClockConfig {
+        SimulationClockConfig { speed, ..self }
+    }
+    pub const fn with_turn_duration(self, turn_duration: Duration) -> SimulationClockConfig {
+        SimulationClockConfig {
+            turn_duration,
+            ..self
+        }
+    }
+}
+
+impl Default for SimulationClockConfig {
+    fn default() -> SimulationClockConfig {
+        SimulationClockConfig::new(SimulationTimestamp::EPOCH)
+    }
+}
+
+/// Everything a single `SimulationClock::tick_detailed` call did
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TickReport {
+    /// Simulation Time advanced, negative when the clock ran backwards
+    pub sim_delta: SignedDuration,
+    /// Turns completed during the tick
+    pub turns_completed: u64,
+    /// Metric Units whose boundary was crossed
+    pub rollovers: RolloverFlags,
+    /// Scheduled events that became due, in time order
+    pub due_events: Vec<EventHandle>,
 }
 
 impl SimulationClock {
     /// Create a new Simulation Clock starting at `origin` seconds.
     pub fn from_seconds(epoch_seconds: u64) -> SimulationClock {
-        SimulationClock {
-            clock_time: Duration::from_secs(epoch_seconds),
+        let clock = SimulationClock {
+            clock_time: SimulationTimestamp::from_epoch_seconds(epoch_seconds),
             clock_mode: ClockMode::RealTime,
             clock_speed: 0.0,
+            clock_speed_min: 0.0,
+            clock_speed_max: default_clock_speed_max(),
+            clock_speed_ratio: None,
             turn_duration: Duration::from_secs_f64(3.0),
             turn_time_remaining: Default::default(),
+            turn_stall_time: Default::default(),
+            turn_grace: Default::default(),
+            turn_epsilon: default_turn_epsilon(),
+            turn_number: 0,
+            turn_start: default_turn_start(),
+            turn_subdivisions: default_turn_subdivisions(),
+            monotonic: false,
+            calendar_cycle: None,
+            local_offset: SignedDuration::default(),
+            session_real_time: Duration::default(),
+            timeline: None,
+            rollover_callbacks: RolloverCallbacks::default(),
+            turn_just_completed: false,
+            speed_sample_window: 0,
+            speed_samples: VecDeque::new(),
+            atomic_epoch: AtomicEpoch::default(),
+            scheduled_events: EventQueue::new(),
+            next_event_handle: 0,
+        };
+        clock.publish_time();
+        clock
+    }
+    /// Create a Simulation Clock at a random time for fuzz and property tests.
+    ///
+    /// The clock starts within the first 10,000 Metric Years, leaving ample headroom for ticks,
+    /// with a random Clock Speed in `(0.0, 1000.0]` and a random Clock Mode. Other settings are
+    /// left at their defaults.
+    #[cfg(feature = "rng")]
+    pub fn from_rng(rng: &mut impl rand_core::RngCore) -> SimulationClock {
+        let epoch_seconds = rng.next_u64() % (10_000 * SECONDS_PER_METRIC_YEAR);
+        let mut clock =
+            SimulationClock::starting_at(SimulationTimestamp::from_epoch_seconds(epoch_seconds));
+        clock.set_clock_speed((rng.next_u32() as f64 + 1.0) / (u32::MAX as f64 + 1.0) * 1000.0);
+        match rng.next_u32() % 3 {
+            0 => {}
+            1 => clock.enable_turn_mode(),
+            _ => clock.enable_strict_turn_mode(),
         }
+        clock
     }
+    /// Create a new Simulation Clock starting at `timestamp` with default speed, mode, and Turn
+    /// settings.
+    pub fn at(timestamp: SimulationTimestamp) -> SimulationClock {
+        SimulationClock::starting_at(timestamp)
+    }
+    /// Create a Simulation Clock from `config`, with every other setting at its default.
     ///
+    /// The speed is clamped to the default speed limits and a NaN speed is ignored, as with
+    /// `set_clock_speed`.
+    pub fn from_config(config: &SimulationClockConfig) -> SimulationClock {
+        let mut clock = SimulationClock {
+            turn_duration: config.turn_duration,
+            ..SimulationClock::starting_at(config.start)
+        };
+        clock.set_clock_speed(config.speed);
+        match config.mode {
+            ClockMode::TurnBased => clock.enable_turn_mode(),
+            ClockMode::StrictTurn => clock.enable_strict_turn_mode(),
+            ClockMode::RealTime => {}
+        }
+        clock
+    }
+    /// Create a new Simulation Clock at `clock_time` with otherwise default settings.
+    fn starting_at(clock_time: SimulationTimestamp) -> SimulationClock {
+        let clock = Self {
+            clock_time,
+            ..Default::default()
+        };
+        clock.publish_time();
+        clock
+    }
+    /// Create a new Simulation Clock starting at the given Metric Timestamp.
     pub fn from_metric_timestamp(
         year: u64,
         month: u64,
@@ -100,281 +368,4810 @@ impl SimulationClock {
         epoch_seconds += day * 100_000;
         epoch_seconds += hour * 10_000;
         epoch_seconds += minute * 100;
-        epoch_seconds += second * 1;
-        let clock_time = Duration::from_secs(epoch_seconds);
-        Self {
-            clock_time,
-            ..Default::default()
-        }
+        epoch_seconds += second;
+        SimulationClock::starting_at(SimulationTimestamp::from_epoch_seconds(epoch_seconds))
+    }
+    /// Create a new Simulation Clock starting at the given Metric Timestamp, failing with
+    /// `MetricError::Overflow` if it is not representable.
+    pub fn try_from_metric_timestamp(
+        year: u64,
+        month: u64,
+        week: u64,
+        day: u64,
+        hour: u64,
+        minute: u64,
+        second: u64,
+    ) -> Result<SimulationClock, MetricError> {
+        checked_epoch_seconds([year, month, week, day, hour, minute, second])
+            .map(|epoch_seconds| {
+                SimulationClock::starting_at(SimulationTimestamp::from_epoch_seconds(epoch_seconds))
+            })
+            .ok_or(MetricError::Overflow)
     }
     pub fn current_timestamp(&self) -> SimulationTimestamp {
-        SimulationTimestamp::from(self.clock_time.clone())
+        self.clock_time
     }
+    /// Current local Date Time, shifted by the local offset and wrapped to the calendar cycle if
+    /// one is set.
     pub fn current_datetime(&self) -> SimulationDateTime {
-        SimulationDateTime::from(self.clock_time.clone())
+        self.calendar_datetime(self.clock_time.saturating_add_signed(self.local_offset))
+    }
+    /// Current Timestamp and local Date Time together, as returned by `current_timestamp` and
+    /// `current_datetime`.
+    pub fn current(&self) -> (SimulationTimestamp, SimulationDateTime) {
+        let time = self.clock_time;
+        let datetime = self.calendar_datetime(time.saturating_add_signed(self.local_offset));
+        (time, datetime)
+    }
+    /// Simulation Time remaining until `deadline`, or zero once it has passed.
+    pub fn time_until(&self, deadline: SimulationTimestamp) -> SimulationDuration {
+        deadline.duration_since(&self.clock_time)
+    }
+    /// Simulation Time elapsed since `moment`, or zero while it is still ahead.
+    pub fn time_since(&self, moment: SimulationTimestamp) -> SimulationDuration {
+        self.clock_time.duration_since(&moment)
+    }
+    /// Whether Simulation Time has reached `timestamp`.
+    pub fn is_past(&self, timestamp: SimulationTimestamp) -> bool {
+        timestamp <= self.clock_time
+    }
+    /// Local Date Time `duration` from now, as `current_datetime` will show it then, without
+    /// advancing the clock. Saturates at the latest representable Timestamp.
+    pub fn datetime_after(&self, duration: Duration) -> SimulationDateTime {
+        let time = self
+            .clock_time
+            .saturating_add(duration)
+            .saturating_add_signed(self.local_offset);
+        self.calendar_datetime(time)
+    }
+    /// Current Date Time without the local offset, wrapped to the calendar cycle if one is set.
+    pub fn utc_datetime(&self) -> SimulationDateTime {
+        self.calendar_datetime(self.clock_time)
+    }
+    fn calendar_datetime(&self, time: SimulationTimestamp) -> SimulationDateTime {
+        match self.calendar_cycle {
+            Some(period) => {
+                let wrapped = time.as_epoch_nanos().rem_euclid(period.as_nanos() as i128);
+                SimulationDateTime::from(SimulationTimestamp::from_nanos(wrapped))
+            }
+            None => SimulationDateTime::from(time),
+        }
+    }
+    pub fn local_offset(&self) -> SignedDuration {
+        self.local_offset
+    }
+    /// Shift the Date Time shown by `current_datetime` without changing Simulation Time, so
+    /// clocks sharing an epoch can each show a local time.
+    ///
+    /// A negative offset that reaches past the epoch shows a Date Time before it.
+    pub fn set_local_offset(&mut self, offset: SignedDuration) {
+        self.local_offset = offset;
+    }
+    pub fn calendar_cycle(&self) -> Option<Duration> {
+        self.calendar_cycle
+    }
+    /// Make `current_datetime` repeat every `period` while Simulation Time keeps counting up.
+    ///
+    /// A zero `period` removes the cycle.
+    pub fn set_calendar_cycle(&mut self, period: Duration) {
+        self.calendar_cycle = Some(period).filter(|period| !period.is_zero());
     }
     pub fn current_epoch_seconds(&self) -> f64 {
         self.clock_time.as_secs_f64()
     }
+    /// Total Real Time ticked since this clock was created or loaded, ignoring speed and turns.
+    pub fn session_real_time(&self) -> Duration {
+        self.session_real_time
+    }
+    /// Whether two clocks match within `time_tolerance` of Simulation Time and Turn time
+    /// remaining and within `speed_tolerance` of Clock Speed, with all other state equal.
+    pub fn approx_eq(
+        &self,
+        other: &SimulationClock,
+        time_tolerance: Duration,
+        speed_tolerance: f64,
+    ) -> bool {
+        let within = |a: Duration, b: Duration| a.abs_diff(b) <= time_tolerance;
+        self.clock_time
+            .signed_duration_since(&other.clock_time)
+            .magnitude()
+            <= time_tolerance
+            && within(self.turn_time_remaining, other.turn_time_remaining)
+            && (self.clock_speed - other.clock_speed).abs() <= speed_tolerance
+            && self.clock_mode == other.clock_mode
+            && self.turn_duration == other.turn_duration
+            && self.turn_number == other.turn_number
+    }
+    /// Simulation Time as its two most significant non-zero Metric units, such as
+    /// `"2 metric years, 3 metric months"`.
+    ///
+    /// Times before Simulation Epoch show the span back to the epoch with a leading `-`, such as
+    /// `"-2 metric years, 3 metric months"`.
+    pub fn uptime_string(&self) -> String {
+        let since_epoch = self
+            .clock_time
+            .signed_duration_since(&SimulationTimestamp::EPOCH);
+        let seconds = since_epoch.magnitude().as_secs();
+        let units = [
+            (seconds / SECONDS_PER_METRIC_YEAR, MetricScale::Year),
+            (
+                seconds % SECONDS_PER_METRIC_YEAR / SECONDS_PER_METRIC_MONTH,
+                MetricScale::Month,
+            ),
+            (
+                seconds % SECONDS_PER_METRIC_MONTH / SECONDS_PER_METRIC_WEEK,
+                MetricScale::Week,
+            ),
+            (
+                seconds % SECONDS_PER_METRIC_WEEK / SECONDS_PER_METRIC_DAY,
+                MetricScale::Day,
+            ),
+            (
+                seconds % SECONDS_PER_METRIC_DAY / SECONDS_PER_METRIC_HOUR,
+                MetricScale::Hour,
+            ),
+            (
+                seconds % SECONDS_PER_METRIC_HOUR / SECONDS_PER_METRIC_MINUTE,
+                MetricScale::Minute,
+            ),
+            (seconds % SECONDS_PER_METRIC_MINUTE, MetricScale::Second),
+        ];
+        let parts: Vec<String> = units
+            .iter()
+            .filter(|(value, _)| *value != 0)
+            .take(2)
+            .map(|(value, scale)| {
+                let plural = if *value == 1 { "" } else { "s" };
+                format!("{} {}{}", value, scale.name().to_lowercase(), plural)
+            })
+            .collect();
+        if parts.is_empty() {
+            return String::from("0 metric seconds");
+        }
+        let sign = if since_epoch.is_negative() { "-" } else { "" };
+        format!("{}{}", sign, parts.join(", "))
+    }
+    /// Whole-second Simulation Time as last published by `tick`, read without locking.
+    pub fn atomic_time(&self) -> SimulationTimestamp {
+        SimulationTimestamp::from_signed_epoch_seconds(self.atomic_epoch.load())
+    }
+    /// Handle that reads the published Simulation Time from another thread.
+    pub fn time_reader(&self) -> ClockReader {
+        self.atomic_epoch.reader()
+    }
+    fn publish_time(&self) {
+        self.atomic_epoch.store(self.clock_time.epoch_seconds());
+    }
     pub fn clock_speed(&self) -> f64 {
         self.clock_speed
     }
+    /// Set the Clock Speed Multiplier, clamped into the configured speed limits.
+    ///
+    /// A NaN speed is ignored and leaves the current speed unchanged.
     pub fn set_clock_speed(&mut self, speed: f64) {
+        if !speed.is_nan() {
+            self.clock_speed = speed.clamp(self.clock_speed_min, self.clock_speed_max);
+            self.clock_speed_ratio = None;
+        }
+    }
+    /// Exact Clock Speed as `(numerator, denominator)` when set by `set_clock_speed_ratio`.
+    pub fn clock_speed_ratio(&self) -> Option<(u32, u32)> {
+        self.clock_speed_ratio
+    }
+    /// Set the Clock Speed to exactly `num / den`.
+    ///
+    /// While a ratio is set, `tick` scales Real Time with integer nanosecond arithmetic so the same
+    /// ticks produce the same Simulation Time on every platform, as lockstep multiplayer needs.
+    /// A zero `den` is ignored. A ratio outside the speed limits is clamped by `set_clock_speed`
+    /// instead and loses its exactness.
+    pub fn set_clock_speed_ratio(&mut self, num: u32, den: u32) {
+        if den == 0 {
+            return;
+        }
+        let speed = num as f64 / den as f64;
+        if speed < self.clock_speed_min || speed > self.clock_speed_max {
+            self.set_clock_speed(speed);
+            return;
+        }
         self.clock_speed = speed;
+        self.clock_speed_ratio = Some((num, den));
+    }
+    /// Scale `delta` of Real Time into Simulation Time by the non-negative Clock Speed.
+    fn scale_real_time(&self, delta: Duration) -> Duration {
+        match self.clock_speed_ratio {
+            Some((num, den)) => duration_from_nanos(delta.as_nanos() * num as u128 / den as u128)
+                .unwrap_or(Duration::MAX),
+            None => scale_duration(delta, self.clock_speed),
+        }
+    }
+    /// Simulation Time that `delta` of Real Time advances at the current Clock Speed, or `None`
+    /// if it does not fit in a `Duration` or the Clock Speed is negative.
+    pub fn checked_mul_speed(&self, delta: Duration) -> Option<Duration> {
+        match self.clock_speed_ratio {
+            Some((num, den)) => duration_from_nanos(delta.as_nanos() * num as u128 / den as u128),
+            None => checked_scale_duration(delta, self.clock_speed),
+        }
+    }
+    /// Timestamp `duration` after the current Simulation Time, or `None` on overflow.
+    pub fn checked_add(&self, duration: Duration) -> Option<SimulationTimestamp> {
+        self.current_timestamp().checked_add(duration)
+    }
+    /// Timestamp `duration` before the current Simulation Time, or `None` on overflow.
+    pub fn checked_sub(&self, duration: Duration) -> Option<SimulationTimestamp> {
+        self.current_timestamp().checked_sub(duration)
+    }
+    /// Set the Clock Speed Multiplier, rejecting negative, NaN, and infinite speeds.
+    ///
+    /// Accepted speeds are clamped into the configured speed limits.
+    pub fn try_set_clock_speed(&mut self, speed: f64) -> Result<(), MetricError> {
+        if !speed.is_finite() || speed < 0.0 {
+            return Err(MetricError::InvalidSpeed(speed));
+        }
+        self.set_clock_speed(speed);
+        Ok(())
+    }
+    /// Configured `(min, max)` Clock Speed limits.
+    pub fn speed_limits(&self) -> (f64, f64) {
+        (self.clock_speed_min, self.clock_speed_max)
+    }
+    /// Restrict the Clock Speed to `min..=max`, clamping the current speed into the new range.
+    ///
+    /// Defaults to `0.0..=f64::MAX`. Limits that are NaN or where `min > max` are ignored.
+    pub fn set_speed_limits(&mut self, min: f64, max: f64) {
+        if min.is_nan() || max.is_nan() || min > max {
+            return;
+        }
+        self.clock_speed_min = min;
+        self.clock_speed_max = max;
+        let clamped = self.clock_speed.clamp(min, max);
+        if clamped != self.clock_speed {
+            self.clock_speed_ratio = None;
+        }
+        self.clock_speed = clamped;
+    }
+    pub fn mode(&self) -> ClockMode {
+        self.clock_mode
+    }
+    /// Whether the clock is in either Turn-Based or Strict Turn mode.
+    pub fn is_turn_based(&self) -> bool {
+        matches!(
+            self.clock_mode,
+            ClockMode::TurnBased | ClockMode::StrictTurn
+        )
+    }
+    pub fn is_real_time(&self) -> bool {
+        self.clock_mode == ClockMode::RealTime
+    }
+    /// Switch to Strict Turn mode, where time only moves through `advance_turn`.
+    ///
+    /// Any Turn in progress is abandoned without advancing time further.
+    pub fn enable_strict_turn_mode(&mut self) {
+        if self.clock_mode != ClockMode::StrictTurn {
+            self.clock_mode = ClockMode::StrictTurn;
+            self.turn_time_remaining = Duration::default();
+            self.turn_stall_time = Duration::default();
+            self.turn_start = self.clock_time;
+        }
     }
     pub fn enable_turn_mode(&mut self) {
         if self.clock_mode == ClockMode::RealTime {
             self.clock_mode = ClockMode::TurnBased;
-            self.turn_time_remaining = self.turn_duration.clone();
+            self.turn_time_remaining = self.turn_duration;
+            self.turn_start = self.clock_time;
         }
     }
+    /// Number of Turns `self` is ahead of `other`, negative when `self` is behind.
+    pub fn turn_drift(&self, other: &SimulationClock) -> i64 {
+        let drift = self.turn_number as i128 - other.turn_number as i128;
+        drift.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+    /// Duration of a Turn as a Simulation Duration for display in Metric units.
+    pub fn turn_duration_metric(&self) -> SimulationDuration {
+        SimulationDuration::new(self.turn_duration)
+    }
+    /// In-world Metric time represented by one Turn, decomposed into calendar components.
+    pub fn metric_time_per_turn(&self) -> SimulationDateTime {
+        SimulationDateTime::from(self.turn_duration)
+    }
+    /// Number of Turns that make up one `scale` unit.
+    pub fn turns_per_metric_unit(&self, scale: MetricScale) -> f64 {
+        scale.seconds_per_unit() as f64 / self.turn_duration.as_secs_f64()
+    }
+    /// Number of Turns advanced so far.
+    pub fn turn_number(&self) -> u64 {
+        self.turn_number
+    }
+    /// Whether the current Turn has run out and its grace window has elapsed.
     pub fn turn_complete(&self) -> bool {
-        self.turn_time_remaining.is_zero()
+        self.turn_time_remaining.is_zero() && self.turn_stall_time >= self.turn_grace
     }
-    pub fn advance_turn(&mut self) {
+    /// Whether the most recent `tick` is the one that ran the current Turn out.
+    ///
+    /// Unlike `turn_complete`, this is only true until the next `tick` or `advance_turn`, which
+    /// makes it suitable for edge-triggered "on Turn end" reactions.
+    pub fn just_completed_turn(&self) -> bool {
+        self.turn_just_completed
+    }
+    pub fn turn_grace(&self) -> Duration {
+        self.turn_grace
+    }
+    /// Set how long, in Real Time, a Turn must sit at zero remaining before `turn_complete`
+    /// reports it and `advance_turn` starts the next one. Defaults to zero.
+    ///
+    /// The boundary is inclusive: a Turn stalled for exactly `grace` is complete, so the default
+    /// completes a Turn as soon as it runs out.
+    pub fn set_turn_grace(&mut self, grace: Duration) {
+        self.turn_grace = grace;
+    }
+    pub fn turn_epsilon(&self) -> Duration {
+        self.turn_epsilon
+    }
+    /// Set how close to zero the time remaining in a Turn must get before the Turn completes.
+    ///
+    /// Floating point deltas rarely land exactly on a Turn boundary, so a Turn left with no more
+    /// than `epsilon` remaining is finished off at its boundary and `turn_complete` and
+    /// `advance_turn` treat it as done. Defaults to one microsecond.
+    ///
+    /// A running Turn already within the new `epsilon` of its end is finished off immediately.
+    pub fn set_turn_epsilon(&mut self, epsilon: Duration) {
+        self.turn_epsilon = epsilon;
         if self.clock_mode == ClockMode::TurnBased
-            && self.turn_time_remaining == Duration::default()
+            && !self.turn_time_remaining.is_zero()
+            && self.turn_time_remaining <= epsilon
         {
-            self.turn_time_remaining = self.turn_duration.clone();
+            self.advance_sim(self.turn_time_remaining);
+        }
+    }
+    /// Rewind Simulation Time by the portion of the current Turn already consumed and restart the
+    /// Turn from its full duration, saturating at the earliest representable Timestamp.
+    ///
+    /// Like `set_time`, this is a deliberate jump and bypasses the monotonic guard. Outside
+    /// Turn-Based mode the clock is left unchanged.
+    pub fn snap_to_turn_start(&mut self) {
+        if self.clock_mode != ClockMode::TurnBased {
+            return;
+        }
+        let consumed = self.turn_duration.saturating_sub(self.turn_time_remaining);
+        self.clock_time = self.clock_time.saturating_sub(consumed);
+        self.turn_time_remaining = self.turn_duration;
+        self.turn_stall_time = Duration::default();
+        self.turn_just_completed = false;
+        self.turn_start = self.clock_time;
+        self.publish_time();
+    }
+    /// Start the next Turn once the current one is complete.
+    ///
+    /// In Strict Turn mode this unconditionally jumps Simulation Time forward by one Turn Duration.
+    pub fn advance_turn(&mut self) {
+        if self.clock_mode == ClockMode::StrictTurn {
+            let before = self.clock_time.epoch_seconds();
+            self.clock_time = self.clock_time.saturating_add(self.turn_duration);
+            self.turn_number += 1;
+            self.turn_start = self.clock_time;
+            self.publish_time();
+            self.fire_rollovers(before);
+        } else if self.clock_mode == ClockMode::TurnBased && self.turn_complete() {
+            self.start_next_turn();
         }
     }
+    /// Restart the Turn timer for the next Turn, regardless of the grace window.
+    fn start_next_turn(&mut self) {
+        self.turn_time_remaining = self.turn_duration;
+        self.turn_stall_time = Duration::default();
+        self.turn_just_completed = false;
+        self.turn_number += 1;
+        self.turn_start = self.clock_time;
+    }
     pub fn disable_turn_mode(&mut self) {
-        if self.clock_mode == ClockMode::TurnBased {
+        if self.clock_mode != ClockMode::RealTime {
             self.clock_mode = ClockMode::RealTime;
             self.turn_time_remaining = Duration::default();
+            self.turn_stall_time = Duration::default();
         }
     }
+    /// Real Time ticked since the current Turn completed without a call to `advance_turn`.
+    pub fn stalled_duration(&self) -> Duration {
+        self.turn_stall_time
+    }
+    /// Whether a completed Turn has gone un-advanced for longer than `threshold`.
+    pub fn is_starved(&self, threshold: Duration) -> bool {
+        self.turn_stall_time > threshold
+    }
+    /// Advance the clock by `delta` of Real Time scaled by the Clock Speed.
+    ///
+    /// In Strict Turn mode Simulation Time does not move; only `advance_turn` advances it.
+    ///
+    /// Scaled deltas too large for a `Duration`, and Simulation Time pushed past the latest
+    /// representable Timestamp, clamp there instead of panicking.
+    ///
+    /// A negative Clock Speed, only reachable by lowering the speed limits below zero, runs the
+    /// clock backwards, past the epoch if need be.
     pub fn tick(&mut self, delta: Duration) {
-        let delta = delta.mul_f64(self.clock_speed.into());
-        match self.clock_mode {
+        self.tick_at(delta, None, false);
+    }
+    /// Tick as `tick` does, for call sites that rely on ticking never panicking.
+    ///
+    /// Extreme deltas and speeds clamp Simulation Time at the ends of the representable
+    /// Timestamp range and the Turn stall time at `Duration::MAX`. Unlike `tick`, a monotonic
+    /// clock with a negative Clock Speed saturates at its current time instead of tripping the
+    /// debug assertion set up by `set_monotonic`.
+    pub fn tick_saturating(&mut self, delta: Duration) {
+        self.tick_at(delta, None, true);
+    }
+    /// Tick as `tick` does, but scale `delta` by `speed` instead of the stored Clock Speed for
+    /// this call only.
+    ///
+    /// `speed` is clamped into the speed limits like `set_clock_speed`, and a NaN `speed` is
+    /// ignored so the tick uses the stored Clock Speed.
+    pub fn tick_scaled(&mut self, delta: Duration, speed: f64) {
+        let speed =
+            (!speed.is_nan()).then(|| speed.clamp(self.clock_speed_min, self.clock_speed_max));
+        self.tick_at(delta, speed, false);
+    }
+    /// Tick by `delta` scaled by `speed`, or by the stored Clock Speed when `speed` is `None`.
+    ///
+    /// With `saturating`, a monotonic clock holds its time rather than rewinding.
+    fn tick_at(&mut self, delta: Duration, speed: Option<f64>, saturating: bool) {
+        let start = self.clock_time;
+        let before = self.clock_time.epoch_seconds();
+        let turn_running =
+            self.clock_mode == ClockMode::TurnBased && !self.turn_time_remaining.is_zero();
+        self.turn_just_completed = false;
+        self.session_real_time = self.session_real_time.saturating_add(delta);
+        if self.clock_mode == ClockMode::StrictTurn {
+            self.record_speed_sample(delta, start);
+            return;
+        }
+        if self.clock_mode == ClockMode::TurnBased && self.turn_time_remaining.is_zero() {
+            self.turn_stall_time = self.turn_stall_time.saturating_add(delta);
+        }
+        let clock_speed = speed.unwrap_or(self.clock_speed);
+        if clock_speed < 0.0 {
+            let mut rewound = self
+                .clock_time
+                .saturating_sub(scale_duration(delta, -clock_speed));
+            if saturating && self.monotonic {
+                rewound = self.clock_time;
+            }
+            self.guard_monotonic(rewound);
+            self.clock_time = rewound;
+            self.publish_time();
+        } else {
+            let sim_delta = match speed {
+                Some(speed) => scale_duration(delta, speed),
+                None => self.scale_real_time(delta),
+            };
+            let remaining = self.turn_time_remaining;
+            self.advance_sim(sim_delta);
+            if turn_running && self.turn_time_remaining.is_zero() && sim_delta > remaining {
+                // The Real Time left over once the Turn ran out already counts as stalled.
+                let overshoot = sim_delta - remaining;
+                let leftover = delta
+                    .as_nanos()
+                    .checked_mul(overshoot.as_nanos())
+                    .and_then(|nanos| duration_from_nanos(nanos / sim_delta.as_nanos()))
+                    .unwrap_or_else(|| {
+                        scale_duration(delta, overshoot.as_secs_f64() / sim_delta.as_secs_f64())
+                    });
+                self.turn_stall_time = self.turn_stall_time.saturating_add(leftover);
+            }
+        }
+        self.turn_just_completed = turn_running && self.turn_time_remaining.is_zero();
+        self.record_speed_sample(delta, start);
+        self.apply_timeline();
+        self.fire_rollovers(before);
+    }
+    /// Call `callback` from `tick` every time the boundary of `scale` is crossed.
+    ///
+    /// A single large tick that crosses several boundaries calls `callback` once per boundary,
+    /// after the whole tick has been applied. Callbacks are kept across `reset` but are neither
+    /// serialized nor cloned.
+    pub fn on_rollover(
+        &mut self,
+        scale: MetricScale,
+        callback: impl FnMut(&SimulationClock) + Send + 'static,
+    ) {
+        self.rollover_callbacks.push(scale, Box::new(callback));
+    }
+    fn fire_rollovers(&mut self, before: i64) {
+        let after = self.clock_time.epoch_seconds();
+        if after <= before || self.rollover_callbacks.is_empty() {
+            return;
+        }
+        let mut callbacks = self.rollover_callbacks.take();
+        for (scale, callback) in callbacks.iter_mut() {
+            let unit = scale.seconds_per_unit() as i128;
+            for _ in 0..((after as i128).div_euclid(unit) - (before as i128).div_euclid(unit)) {
+                callback(self);
+            }
+        }
+        self.rollover_callbacks.restore(callbacks);
+    }
+    /// Average Simulation Time advanced per second of Real Time over the last ticks in the speed
+    /// sample window.
+    ///
+    /// Stalled Turns and rewinds are included, so this is the effective speed rather than the
+    /// configured one. With no Real Time sampled, the current Clock Speed is returned instead.
+    pub fn average_speed(&self) -> f64 {
+        let (real, simulated) = self
+            .speed_samples
+            .iter()
+            .fold((0.0, 0.0), |(real, simulated), sample| {
+                (real + sample.0, simulated + sample.1)
+            });
+        if real == 0.0 {
+            return self.clock_speed;
+        }
+        simulated / real
+    }
+    pub fn speed_sample_window(&self) -> usize {
+        self.speed_sample_window
+    }
+    /// Set how many of the most recent ticks `average_speed` covers. Zero, the default, turns
+    /// sampling off.
+    pub fn set_speed_sample_window(&mut self, ticks: usize) {
+        self.speed_sample_window = ticks;
+        while self.speed_samples.len() > ticks {
+            self.speed_samples.pop_front();
+        }
+    }
+    fn record_speed_sample(&mut self, real_delta: Duration, start: SimulationTimestamp) {
+        if self.speed_sample_window == 0 {
+            return;
+        }
+        if self.speed_samples.len() == self.speed_sample_window {
+            self.speed_samples.pop_front();
+        }
+        let simulated = self.clock_time.as_secs_f64() - start.as_secs_f64();
+        self.speed_samples
+            .push_back((real_delta.as_secs_f64(), simulated));
+    }
+    /// Attach a Timeline whose speed changes are applied as `tick` reaches them, replacing any
+    /// Timeline already attached.
+    pub fn attach_timeline(&mut self, timeline: Timeline) {
+        self.timeline = Some(timeline);
+        self.apply_timeline();
+    }
+    /// Detach the current Timeline, returning its unapplied keyframes.
+    pub fn detach_timeline(&mut self) -> Option<Timeline> {
+        self.timeline.take()
+    }
+    pub fn timeline(&self) -> Option<&Timeline> {
+        self.timeline.as_ref()
+    }
+    fn apply_timeline(&mut self) {
+        let now = self.current_timestamp();
+        if let Some(speed) = self
+            .timeline
+            .as_mut()
+            .and_then(|timeline| timeline.take_due(&now))
+        {
+            self.set_clock_speed(speed);
+        }
+    }
+    /// Advance the clock by `parent_sim_delta` of a parent clock's Simulation Time multiplied by
+    /// `scale`, ignoring this clock's own Clock Speed.
+    ///
+    /// This lets a nested simulation run as a fixed multiple of its parent. Turn mode is respected
+    /// as in `advance_sim`, and a NaN or non-positive `scale` leaves the clock where it is.
+    pub fn tick_from_parent(&mut self, parent_sim_delta: Duration, scale: f64) {
+        let before = self.clock_time.epoch_seconds();
+        let turn_running =
+            self.clock_mode == ClockMode::TurnBased && !self.turn_time_remaining.is_zero();
+        self.advance_sim(scale_duration(parent_sim_delta, scale));
+        self.turn_just_completed = turn_running && self.turn_time_remaining.is_zero();
+        self.apply_timeline();
+        self.fire_rollovers(before);
+    }
+    /// Tick by the Real Time between `last` and `now`, ticking zero if `now` is before `last`.
+    pub fn tick_between(&mut self, last: Instant, now: Instant) {
+        self.tick(now.saturating_duration_since(last));
+    }
+    /// Tick the clock, letting a large `delta` in Turn mode run through as many Turns as it spans,
+    /// and report how many Turns were completed.
+    ///
+    /// Unlike `tick`, which stops at the end of the current Turn, each Turn completed here is
+    /// advanced automatically so lagging callers can batch-process the Turns they missed. The last
+    /// Turn completed is left for the caller to advance. Outside Turn mode, with a negative Clock
+    /// Speed, or while the current Turn is already complete this behaves exactly like `tick` and
+    /// reports zero.
+    pub fn tick_catch_up(&mut self, delta: Duration) -> u64 {
+        if self.clock_mode != ClockMode::TurnBased
+            || self.clock_speed < 0.0
+            || self.turn_time_remaining.is_zero()
+        {
+            self.tick(delta);
+            return 0;
+        }
+        let start = self.clock_time;
+        let before = self.clock_time.epoch_seconds();
+        self.session_real_time = self.session_real_time.saturating_add(delta);
+        let turns = self.advance_sim_turns(self.scale_real_time(delta));
+        self.turn_just_completed = self.turn_time_remaining.is_zero();
+        self.record_speed_sample(delta, start);
+        self.apply_timeline();
+        self.fire_rollovers(before);
+        turns
+    }
+    /// Advance `sim_delta` of Simulation Time through consecutive Turns, returning how many
+    /// Turns were completed.
+    fn advance_sim_turns(&mut self, mut sim_delta: Duration) -> u64 {
+        let mut turns = 0;
+        while !self.turn_time_remaining.is_zero() && sim_delta >= self.turn_time_remaining {
+            sim_delta -= self.turn_time_remaining;
+            self.advance_sim(self.turn_time_remaining);
+            turns += 1;
+            if sim_delta.is_zero() {
+                break;
+            }
+            self.start_next_turn();
+        }
+        let turn_running = !self.turn_time_remaining.is_zero();
+        self.advance_sim(sim_delta);
+        if turn_running && self.turn_time_remaining.is_zero() {
+            turns += 1;
+        }
+        turns
+    }
+    /// Advance Simulation Time by `years` Metric Years, returning the Turns completed.
+    ///
+    /// See `advance_units` for how Turn modes are handled.
+    pub fn advance_years(&mut self, years: u64) -> Result<u64, MetricError> {
+        self.advance_units(years, MetricScale::Year)
+    }
+    /// Advance Simulation Time by `days` Metric Days, returning the Turns completed.
+    ///
+    /// See `advance_units` for how Turn modes are handled.
+    pub fn advance_days(&mut self, days: u64) -> Result<u64, MetricError> {
+        self.advance_units(days, MetricScale::Day)
+    }
+    /// Advance Simulation Time by `hours` Metric Hours, returning the Turns completed.
+    ///
+    /// See `advance_units` for how Turn modes are handled.
+    pub fn advance_hours(&mut self, hours: u64) -> Result<u64, MetricError> {
+        self.advance_units(hours, MetricScale::Hour)
+    }
+    /// Advance Simulation Time by `count` whole `scale` units, ignoring the Clock Speed, and
+    /// return how many Turns were completed along the way.
+    ///
+    /// In Turn-Based mode a Turn that has run out is advanced first, grace window or not, then
+    /// the span runs through as many Turns as it covers like `tick_catch_up`. In Strict Turn mode
+    /// only the whole Turns that fit in the span are advanced. Fails with `MetricError::Overflow`,
+    /// leaving the clock untouched, if the span or the resulting Simulation Time is too large.
+    pub fn advance_units(&mut self, count: u64, scale: MetricScale) -> Result<u64, MetricError> {
+        let span = count
+            .checked_mul(scale.seconds_per_unit())
+            .map(Duration::from_secs)
+            .ok_or(MetricError::Overflow)?;
+        self.clock_time
+            .checked_add(span)
+            .ok_or(MetricError::Overflow)?;
+        let before = self.clock_time.epoch_seconds();
+        let turns = match self.clock_mode {
             ClockMode::RealTime => {
-                self.clock_time += delta;
+                self.advance_sim(span);
+                0
             }
             ClockMode::TurnBased => {
-                if !self.turn_time_remaining.is_zero() {
-                    self.turn_time_remaining = self.turn_time_remaining.saturating_sub(delta);
-                    self.clock_time += delta;
+                if self.turn_time_remaining.is_zero() {
+                    self.start_next_turn();
                 }
+                self.advance_sim_turns(span)
             }
+            ClockMode::StrictTurn if self.turn_duration.is_zero() => 0,
+            ClockMode::StrictTurn => {
+                let turns = span.as_nanos() / self.turn_duration.as_nanos();
+                let stepped = duration_from_nanos(turns * self.turn_duration.as_nanos());
+                self.clock_time += stepped.unwrap_or(span);
+                let turns = turns as u64;
+                self.turn_number += turns;
+                self.turn_start = self.clock_time;
+                self.publish_time();
+                turns
+            }
+        };
+        self.apply_timeline();
+        self.fire_rollovers(before);
+        Ok(turns)
+    }
+    pub fn is_monotonic(&self) -> bool {
+        self.monotonic
+    }
+    /// Make `tick` panic in debug builds if it would move Simulation Time backwards.
+    ///
+    /// The check is compiled out of release builds. Explicit jumps through `set_time` and
+    /// `skip_time` are deliberate and bypass the guard, and `tick_saturating` holds the time
+    /// instead of rewinding.
+    pub fn set_monotonic(&mut self, monotonic: bool) {
+        self.monotonic = monotonic;
+    }
+    fn guard_monotonic(&self, next: SimulationTimestamp) {
+        debug_assert!(
+            !self.monotonic || next >= self.clock_time,
+            "monotonic SimulationClock would rewind from {:?} to {:?}",
+            self.clock_time,
+            next
+        );
+    }
+    /// Jump Simulation Time directly to `timestamp`, leaving Turn state untouched.
+    pub fn set_time(&mut self, timestamp: SimulationTimestamp) {
+        self.clock_time = timestamp;
+        self.publish_time();
+    }
+    /// Return the clock to the Simulation Epoch in Real-Time mode with a fresh Turn counter.
+    ///
+    /// Configuration such as the Clock Speed, Turn Duration, and Turn grace window is preserved.
+    pub fn reset(&mut self) {
+        self.clock_time = SimulationTimestamp::EPOCH;
+        self.clock_mode = ClockMode::RealTime;
+        self.turn_time_remaining = Duration::default();
+        self.turn_stall_time = Duration::default();
+        self.turn_just_completed = false;
+        self.turn_number = 0;
+        self.turn_start = default_turn_start();
+        self.publish_time();
+    }
+    pub fn turn_time_remaining(&self) -> Duration {
+        self.turn_time_remaining
+    }
+    /// Time remaining in the current Turn as a Simulation Duration for display in Metric units.
+    pub fn turn_time_remaining_metric(&self) -> SimulationDuration {
+        SimulationDuration::new(self.turn_time_remaining)
+    }
+    /// Simulation Time at which the current Turn will finish, or `None` outside Turn mode.
+    pub fn next_turn_completion(&self) -> Option<SimulationTimestamp> {
+        match self.clock_mode {
+            ClockMode::TurnBased => Some(self.clock_time.saturating_add(self.turn_time_remaining)),
+            ClockMode::StrictTurn => Some(self.clock_time.saturating_add(self.turn_duration)),
+            ClockMode::RealTime => None,
         }
     }
-}
-
-impl Default for SimulationClock {
-    fn default() -> SimulationClock {
-        SimulationClock {
-            clock_time: Default::default(),
-            clock_mode: ClockMode::RealTime,
-            clock_speed: 1.0,
-            turn_duration: Duration::from_secs(6),
-            turn_time_remaining: Duration::default(),
+    /// Whether the current Turn will run out within `duration` of Simulation Time.
+    ///
+    /// Always false outside Turn mode.
+    pub fn turn_completes_within(&self, duration: Duration) -> bool {
+        self.clock_mode == ClockMode::TurnBased && self.turn_time_remaining <= duration
+    }
+    /// Fraction of the current Turn consumed, from 0.0 to 1.0. Always 0.0 outside Turn mode.
+    pub fn turn_progress(&self) -> f64 {
+        if self.clock_mode != ClockMode::TurnBased || self.turn_duration.is_zero() {
+            return 0.0;
         }
+        let consumed = self.turn_duration.saturating_sub(self.turn_time_remaining);
+        consumed.as_secs_f64() / self.turn_duration.as_secs_f64()
     }
-}
-
-impl std::fmt::Debug for SimulationClock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("SimulationClock")
-            .field("clock_epoch_seconds", &self.current_epoch_seconds())
-            .field("clock_timestamp", &self.current_timestamp())
-            .field("clock_datetime", &self.current_datetime())
-            .field("clock_speed", &self.clock_speed)
-            .field("clock_state", &self.clock_mode)
-            .field("turn_time", &self.turn_duration)
-            .field("turn_remaining", &self.turn_time_remaining)
-            .finish()
+    /// Fraction of the current Metric Year elapsed, from 0.0 at the start of a year towards 1.0.
+    ///
+    /// Read straight from Simulation Time, ignoring the local offset and calendar cycle.
+    pub fn year_phase(&self) -> f64 {
+        let year = SECONDS_PER_METRIC_YEAR as i128 * 1_000_000_000;
+        self.clock_time.as_epoch_nanos().rem_euclid(year) as f64 / year as f64
     }
-}
-
-/// Fixed Timestamp
-#[derive(Clone, Serialize, Deserialize)]
-pub struct SimulationTimestamp(Duration);
-
-impl SimulationTimestamp {
-    pub fn from_epoch_seconds(epoch_seconds: u64) -> SimulationTimestamp {
-        Self(Duration::from_secs(epoch_seconds))
+    /// Fraction of a scenario ending at `end` that has elapsed since the Simulation Epoch,
+    /// clamped to 0.0..=1.0.
+    pub fn scenario_progress(&self, end: SimulationTimestamp) -> f64 {
+        self.scenario_progress_between(SimulationTimestamp::EPOCH, end)
     }
-    pub fn from_components(
-        year: u32,
-        month: u8,
-        week: u8,
-        day: u8,
-        hour: u8,
-        minute: u8,
-        second: u8,
-    ) -> SimulationTimestamp {
-        let mut epoch_seconds = 0.0;
-        epoch_seconds += year as f64 * 100_000_000.0;
-        epoch_seconds += month as f64 * 10_000_000.0;
-        epoch_seconds += week as f64 * 1_000_000.0;
-        epoch_seconds += day as f64 * 100_000.0;
-        epoch_seconds += hour as f64 * 10_000.0;
-        epoch_seconds += minute as f64 * 100.0;
-        epoch_seconds += second as f64 * 1.0;
-        Self(Duration::from_secs_f64(epoch_seconds))
+    /// Fraction of a scenario running from `start` to `end` that has elapsed, clamped to
+    /// 0.0..=1.0. A scenario that ends at or before its start is always complete.
+    pub fn scenario_progress_between(
+        &self,
+        start: SimulationTimestamp,
+        end: SimulationTimestamp,
+    ) -> f64 {
+        if end <= start {
+            return 1.0;
+        }
+        let elapsed = self.clock_time.duration_since(&start).as_duration();
+        let length = end.duration_since(&start).as_duration();
+        (elapsed.as_secs_f64() / length.as_secs_f64()).min(1.0)
     }
-}
-
-impl From<Duration> for SimulationTimestamp {
-    fn from(duration: Duration) -> Self {
-        SimulationTimestamp(duration)
+    /// Render `turn_progress` as a bar such as `"[####------] 40%"` with `width` cells.
+    pub fn turn_progress_bar(&self, width: usize) -> String {
+        let progress = self.turn_progress();
+        let filled = ((progress * width as f64).round() as usize).min(width);
+        format!(
+            "[{}{}] {}%",
+            "#".repeat(filled),
+            "-".repeat(width - filled),
+            (progress * 100.0).round() as u32
+        )
     }
-}
-
-impl From<SimulationDateTime> for SimulationTimestamp {
-    fn from(datetime: SimulationDateTime) -> Self {
-        let mut epoch_seconds = 0;
-        epoch_seconds += datetime.year as u64 * 100_000_000;
-        epoch_seconds += datetime.month as u64 * 10_000_000;
-        epoch_seconds += datetime.week as u64 * 1_000_000;
-        epoch_seconds += datetime.day as u64 * 100_000;
-        epoch_seconds += datetime.hour as u64 * 10_000;
-        epoch_seconds += datetime.minute as u64 * 100;
-        epoch_seconds += datetime.second as u64 * 1;
-        Self(Duration::from_secs(epoch_seconds as u64))
+    pub fn turn_subdivisions(&self) -> u32 {
+        self.turn_subdivisions
     }
-}
-
-impl std::fmt::Debug for SimulationTimestamp {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("SimulationTimestamp")
-            .field("epoch_seconds", &self.0.as_secs())
-            .finish()
+    /// Divide each Turn into `n` evenly spaced phases. Values below one are treated as one.
+    pub fn set_turn_subdivisions(&mut self, n: u32) {
+        self.turn_subdivisions = n.max(1);
     }
-}
-
-impl std::fmt::Display for SimulationTimestamp {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.as_secs_f64())
+    /// Phase of the current Turn, starting at phase 0 when the Turn begins.
+    pub fn current_phase(&self) -> u32 {
+        self.phase_boundaries_reached()
+            .min(self.turn_subdivisions.saturating_sub(1))
     }
-}
-
-/// Data Time of the Simulation
-pub struct SimulationDateTime {
-    pub year: u32,
-    pub month: u8,
-    pub week: u8,
-    pub day: u8,
-    pub hour: u8,
-    pub minute: u8,
-    pub second: u8,
-}
-
-impl SimulationDateTime {
-    pub fn from_epoch_seconds(epoch_seconds: u64) -> Self {
-        let mut epoch_seconds = epoch_seconds;
-        let year = epoch_seconds / 100_000_000;
-        epoch_seconds -= year * 100_000_000;
-        let month = epoch_seconds / 10_000_000;
-        epoch_seconds -= month * 10_000_000;
-        let week = epoch_seconds / 1_000_000;
-        epoch_seconds -= week * 1_000_000;
-        let day = epoch_seconds / 100_000;
-        epoch_seconds -= day * 100_000;
-        let hour = epoch_seconds / 10_000;
-        epoch_seconds -= hour * 10_000;
-        let minute = epoch_seconds / 100;
-        epoch_seconds -= minute * 100;
-        let second = epoch_seconds;
-        Self {
-            year: year as u32,
-            month: month as u8,
-            week: week as u8,
-            day: day as u8,
-            hour: hour as u8,
-            minute: minute as u8,
-            second: second as u8,
+    /// Number of phase boundaries of the current Turn that have been reached, where the end of
+    /// the Turn is the final boundary.
+    fn phase_boundaries_reached(&self) -> u32 {
+        if self.clock_mode != ClockMode::TurnBased || self.turn_duration.is_zero() {
+            return 0;
         }
+        let consumed = self.turn_duration.saturating_sub(self.turn_time_remaining);
+        let reached =
+            consumed.as_nanos() * self.turn_subdivisions as u128 / self.turn_duration.as_nanos();
+        reached as u32
     }
-    pub fn from_components(
-        year: u32,
-        month: u8,
-        week: u8,
-        day: u8,
-        hour: u8,
-        minute: u8,
-        second: u8,
-    ) -> SimulationDateTime {
-        SimulationDateTime {
-            year,
-            month,
-            week,
-            day,
-            hour,
-            minute,
-            second,
+    /// Tick the clock, returning how many Turn phase boundaries were crossed.
+    ///
+    /// With `n` subdivisions a full Turn crosses `n` boundaries, the last being the Turn end.
+    pub fn tick_phases(&mut self, delta: Duration) -> u32 {
+        let before = self.phase_boundaries_reached();
+        self.tick(delta);
+        self.phase_boundaries_reached().saturating_sub(before)
+    }
+    /// Current Simulation Time snapped back to the most recent Turn boundary.
+    ///
+    /// Mid-turn this is the start of the current Turn, and once the Turn is complete it is the
+    /// end of that Turn. In Real-Time mode the current time is returned unchanged.
+    pub fn turn_aligned_timestamp(&self) -> SimulationTimestamp {
+        match self.clock_mode {
+            ClockMode::RealTime | ClockMode::StrictTurn => self.current_timestamp(),
+            ClockMode::TurnBased if self.turn_time_remaining.is_zero() => {
+                self.turn_start.saturating_add(self.turn_duration)
+            }
+            ClockMode::TurnBased => self.turn_start,
+        }
+    }
+    /// Snap `timestamp` to the nearest Turn boundary, with halves rounding later.
+    ///
+    /// Boundaries lie whole Turn Durations before and after the start of the current Turn, in
+    /// any Clock Mode. A zero Turn Duration leaves `timestamp` unchanged, and results saturate at
+    /// the ends of the representable range.
+    pub fn quantize(&self, timestamp: SimulationTimestamp) -> SimulationTimestamp {
+        let turn = self.turn_duration.as_nanos() as i128;
+        if turn == 0 {
+            return timestamp;
+        }
+        let offset = timestamp.as_epoch_nanos() - self.turn_start.as_epoch_nanos();
+        let turns = (offset + turn / 2).div_euclid(turn);
+        SimulationTimestamp::from_epoch_nanos(self.turn_start.as_epoch_nanos() + turns * turn)
+    }
+    /// Turn Number of the Turn containing `timestamp`, counting from the current Turn's
+    /// `turn_number` along the boundaries used by `quantize`.
+    ///
+    /// Timestamps before the first Turn give negative numbers, and a zero Turn Duration maps
+    /// every timestamp to the current Turn.
+    pub fn turn_index_of(&self, timestamp: SimulationTimestamp) -> i64 {
+        let turn = self.turn_duration.as_nanos() as i128;
+        if turn == 0 {
+            return i64::try_from(self.turn_number).unwrap_or(i64::MAX);
+        }
+        let offset = timestamp.as_epoch_nanos() - self.turn_start.as_epoch_nanos();
+        let index = self.turn_number as i128 + offset.div_euclid(turn);
+        index.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+    /// Catch the clock up on `real_elapsed` of Real Time that passed while it was not ticking.
+    ///
+    /// The elapsed time is scaled by the Clock Speed and limited to `cap` if one is given. Returns
+    /// the Simulation Time actually applied, which may be less than requested in Turn mode.
+    pub fn apply_offline(&mut self, real_elapsed: Duration, cap: Option<Duration>) -> Duration {
+        let sim_delta = Duration::try_from_secs_f64(real_elapsed.as_secs_f64() * self.clock_speed)
+            .unwrap_or(Duration::MAX);
+        let sim_delta = cap.map_or(sim_delta, |cap| sim_delta.min(cap));
+        let before = self.clock_time;
+        self.advance_sim(sim_delta);
+        self.clock_time.duration_since(&before).as_duration()
+    }
+    /// Jump Simulation Time forward by `duration` as a scripted event.
+    ///
+    /// Unlike `tick` and `advance_sim` this ignores Clock Speed and does not consume any of the
+    /// current Turn, so Turn state is left exactly as it was.
+    pub fn insert_time(&mut self, duration: Duration) {
+        self.clock_time = self.clock_time.saturating_add(duration);
+        self.turn_start = self.turn_start.saturating_add(duration);
+        self.publish_time();
+    }
+    /// Remove `duration` of Simulation Time as a scripted event, which may rewind the clock past
+    /// the epoch.
+    ///
+    /// This rewinds the clock without touching the Turn counter or the time remaining in the
+    /// current Turn.
+    pub fn skip_time(&mut self, duration: Duration) {
+        self.clock_time = self.clock_time.saturating_sub(duration);
+        self.turn_start = self.turn_start.saturating_sub(duration);
+        self.publish_time();
+    }
+    /// Tick the clock, reporting every Metric Unit whose boundary was crossed.
+    pub fn tick_with_rollovers(&mut self, delta: Duration) -> RolloverFlags {
+        let before = self.clock_time.epoch_seconds();
+        self.tick(delta);
+        RolloverFlags::between(before, self.clock_time.epoch_seconds())
+    }
+    /// Schedule an event to be reported by `tick_detailed` once Simulation Time reaches `at`.
+    pub fn schedule_event(&mut self, at: SimulationTimestamp) -> EventHandle {
+        let handle = EventHandle(self.next_event_handle);
+        self.next_event_handle += 1;
+        self.scheduled_events.push(at, handle);
+        handle
+    }
+    /// Number of scheduled events not yet reported by `tick_detailed`.
+    pub fn pending_events(&self) -> usize {
+        self.scheduled_events.len()
+    }
+    /// Tick as `tick_catch_up` does, reporting everything the tick did in a single [`TickReport`].
+    ///
+    /// Scheduled events become due once Simulation Time reaches them, and each is reported by
+    /// exactly one `tick_detailed`. Events reached through plain `tick` stay pending until the
+    /// next call.
+    pub fn tick_detailed(&mut self, delta: Duration) -> TickReport {
+        let start = self.clock_time;
+        let turns_completed = self.tick_catch_up(delta);
+        let sim_delta = self.clock_time.signed_duration_since(&start);
+        let rollovers =
+            RolloverFlags::between(start.epoch_seconds(), self.clock_time.epoch_seconds());
+        let due_events = self.scheduled_events.drain_until(self.current_timestamp());
+        TickReport {
+            sim_delta,
+            turns_completed,
+            rollovers,
+            due_events,
+        }
+    }
+    /// Advance Simulation Time by `sim_delta` without applying the Clock Speed Multiplier.
+    ///
+    /// Unlike `tick`, `sim_delta` is already Simulation Time. Turn mode is still respected, so no
+    /// time passes while the current Turn is complete or in Strict Turn mode.
+    pub fn advance_sim(&mut self, sim_delta: Duration) {
+        match self.clock_mode {
+            ClockMode::RealTime => {
+                self.clock_time = self.clock_time.saturating_add(sim_delta);
+            }
+            ClockMode::TurnBased => {
+                if !self.turn_time_remaining.is_zero() {
+                    self.turn_time_remaining = self.turn_time_remaining.saturating_sub(sim_delta);
+                    self.clock_time = self.clock_time.saturating_add(sim_delta);
+                    if self.turn_time_remaining <= self.turn_epsilon {
+                        self.clock_time = self.clock_time.saturating_add(self.turn_time_remaining);
+                        self.turn_time_remaining = Duration::ZERO;
+                    }
+                }
+            }
+            ClockMode::StrictTurn => {}
         }
+        self.publish_time();
     }
 }
 
-impl From<Duration> for SimulationDateTime {
-    fn from(duration: Duration) -> Self {
-        SimulationDateTime::from_epoch_seconds(duration.as_secs())
+/// List every observable setting that differs between two clocks, one `name: a | b` line each.
+///
+/// Matching settings are omitted, so clocks that agree produce an empty string. This is meant for
+/// diagnosing a desynced lockstep pair.
+pub fn diff_clocks(a: &SimulationClock, b: &SimulationClock) -> String {
+    let mut diff = String::new();
+    push_diff(
+        &mut diff,
+        "time",
+        a.current_timestamp(),
+        b.current_timestamp(),
+    );
+    push_diff(&mut diff, "mode", a.mode(), b.mode());
+    push_diff(&mut diff, "clock_speed", a.clock_speed(), b.clock_speed());
+    push_diff(
+        &mut diff,
+        "clock_speed_ratio",
+        a.clock_speed_ratio(),
+        b.clock_speed_ratio(),
+    );
+    push_diff(
+        &mut diff,
+        "speed_limits",
+        a.speed_limits(),
+        b.speed_limits(),
+    );
+    push_diff(
+        &mut diff,
+        "turn_duration",
+        a.turn_duration_metric(),
+        b.turn_duration_metric(),
+    );
+    push_diff(
+        &mut diff,
+        "turn_time_remaining",
+        a.turn_time_remaining(),
+        b.turn_time_remaining(),
+    );
+    push_diff(&mut diff, "turn_number", a.turn_number(), b.turn_number());
+    push_diff(&mut diff, "turn_start", a.turn_start, b.turn_start);
+    push_diff(&mut diff, "turn_grace", a.turn_grace(), b.turn_grace());
+    push_diff(
+        &mut diff,
+        "turn_epsilon",
+        a.turn_epsilon(),
+        b.turn_epsilon(),
+    );
+    push_diff(
+        &mut diff,
+        "turn_subdivisions",
+        a.turn_subdivisions(),
+        b.turn_subdivisions(),
+    );
+    push_diff(&mut diff, "monotonic", a.is_monotonic(), b.is_monotonic());
+    push_diff(
+        &mut diff,
+        "calendar_cycle",
+        a.calendar_cycle(),
+        b.calendar_cycle(),
+    );
+    push_diff(
+        &mut diff,
+        "local_offset",
+        a.local_offset(),
+        b.local_offset(),
+    );
+    diff
+}
+
+fn push_diff<T: PartialEq + std::fmt::Debug>(diff: &mut String, name: &str, a: T, b: T) {
+    if a != b {
+        diff.push_str(&format!("{}: {:?} | {:?}\n", name, a, b));
     }
 }
 
-impl From<SimulationTimestamp> for SimulationDateTime {
+/// Sum Metric components ordered from year to second into epoch seconds, or `None` on overflow.
+fn checked_epoch_seconds(components: [u64; 7]) -> Option<u64> {
+    let scales = [
+        SECONDS_PER_METRIC_YEAR,
+        SECONDS_PER_METRIC_MONTH,
+        SECONDS_PER_METRIC_WEEK,
+        SECONDS_PER_METRIC_DAY,
+        SECONDS_PER_METRIC_HOUR,
+        SECONDS_PER_METRIC_MINUTE,
+        1,
+    ];
+    components
+        .iter()
+        .zip(scales)
+        .try_fold(0u64, |total, (value, scale)| {
+            value.checked_mul(scale)?.checked_add(total)
+        })
+}
+
+/// Multiply `duration` by `factor`, saturating at `Duration::MAX`. A NaN or non-positive
+/// `factor` gives zero.
+fn scale_duration(duration: Duration, factor: f64) -> Duration {
+    if factor.is_nan() || factor <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
+/// Multiply `duration` by `factor`, or `None` if the product does not fit in a `Duration` or
+/// `factor` is NaN or negative.
+fn checked_scale_duration(duration: Duration, factor: f64) -> Option<Duration> {
+    if factor.is_nan() || factor < 0.0 {
+        return None;
+    }
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).ok()
+}
+
+/// Build a Duration from a nanosecond count, or `None` if it does not fit.
+fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/// Write Simulation Time as a `Duration`, or as a Date Time string inside `clock_as_datetime`.
+fn serialize_clock_time<S: Serializer>(
+    clock_time: &SimulationTimestamp,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if clock_as_datetime::datetime_form() {
+        serializer.collect_str(&SimulationDateTime::from(*clock_time))
+    } else {
+        clock_time.serialize(serializer)
+    }
+}
+
+/// Accept Simulation Time as a serialized `Duration`, whole epoch seconds, or a Date Time string.
+fn deserialize_clock_time<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SimulationTimestamp, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ClockTime {
+        EpochSeconds(i64),
+        Duration(SimulationTimestamp),
+        DateTime(String),
+    }
+    Ok(match ClockTime::deserialize(deserializer)? {
+        ClockTime::EpochSeconds(epoch_seconds) => {
+            SimulationTimestamp::from_signed_epoch_seconds(epoch_seconds)
+        }
+        ClockTime::Duration(timestamp) => timestamp,
+        ClockTime::DateTime(datetime) => {
+            let datetime: SimulationDateTime =
+                datetime.parse().map_err(serde::de::Error::custom)?;
+            SimulationTimestamp::from(datetime)
+        }
+    })
+}
+
+fn default_clock_speed_max() -> f64 {
+    f64::MAX
+}
+
+fn default_turn_epsilon() -> Duration {
+    Duration::from_micros(1)
+}
+
+fn default_turn_subdivisions() -> u32 {
+    1
+}
+
+fn default_turn_start() -> SimulationTimestamp {
+    SimulationTimestamp::EPOCH
+}
+
+impl From<SimulationTimestamp> for SimulationClock {
     fn from(timestamp: SimulationTimestamp) -> Self {
-        SimulationDateTime::from_epoch_seconds(timestamp.0.as_secs())
+        SimulationClock::at(timestamp)
     }
 }
 
-impl std::fmt::Display for SimulationDateTime {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}-{:02}-{:02}-{:02}@{:02}:{:02}:{:02.4}",
-            &self.year, &self.month, &self.week, &self.day, &self.hour, &self.minute, &self.second
-        )
+impl From<SimulationClockConfig> for SimulationClock {
+    fn from(config: SimulationClockConfig) -> Self {
+        SimulationClock::from_config(&config)
     }
 }
 
-impl std::fmt::Debug for SimulationDateTime {
+impl Serialize for SimulationClock {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SimulationClock::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimulationClock {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[allow(unused_mut)]
+        let mut clock = SimulationClock::deserialize(deserializer)?;
+        #[cfg(feature = "transient-turn-state")]
+        if clock.clock_mode == ClockMode::TurnBased {
+            clock.turn_time_remaining = clock.turn_duration;
+            clock.turn_stall_time = Duration::default();
+            clock.turn_start = clock.clock_time;
+        }
+        clock.publish_time();
+        Ok(clock)
+    }
+}
+
+impl Default for SimulationClock {
+    fn default() -> SimulationClock {
+        SimulationClock {
+            clock_time: SimulationTimestamp::EPOCH,
+            clock_mode: ClockMode::RealTime,
+            clock_speed: 1.0,
+            clock_speed_min: 0.0,
+            clock_speed_max: default_clock_speed_max(),
+            clock_speed_ratio: None,
+            turn_duration: Duration::from_secs(6),
+            turn_time_remaining: Duration::default(),
+            turn_stall_time: Duration::default(),
+            turn_grace: Duration::default(),
+            turn_epsilon: default_turn_epsilon(),
+            turn_number: 0,
+            turn_start: default_turn_start(),
+            turn_subdivisions: default_turn_subdivisions(),
+            monotonic: false,
+            calendar_cycle: None,
+            local_offset: SignedDuration::default(),
+            session_real_time: Duration::default(),
+            timeline: None,
+            rollover_callbacks: RolloverCallbacks::default(),
+            turn_just_completed: false,
+            speed_sample_window: 0,
+            speed_samples: VecDeque::new(),
+            atomic_epoch: AtomicEpoch::default(),
+            scheduled_events: EventQueue::new(),
+            next_event_handle: 0,
+        }
+    }
+}
+
+/// Clocks are equal when all of their simulation state matches exactly.
+///
+/// Clock Speed is compared with `f64` equality, so clocks driven by floating point arithmetic
+/// may differ by rounding; use `approx_eq` for those. Session statistics such as
+/// `session_real_time` are not part of the comparison.
+impl PartialEq for SimulationClock {
+    fn eq(&self, other: &SimulationClock) -> bool {
+        self.clock_time == other.clock_time
+            && self.clock_mode == other.clock_mode
+            && self.clock_speed == other.clock_speed
+            && self.clock_speed_ratio == other.clock_speed_ratio
+            && self.clock_speed_min == other.clock_speed_min
+            && self.clock_speed_max == other.clock_speed_max
+            && self.turn_duration == other.turn_duration
+            && self.turn_time_remaining == other.turn_time_remaining
+            && self.turn_stall_time == other.turn_stall_time
+            && self.turn_grace == other.turn_grace
+            && self.turn_epsilon == other.turn_epsilon
+            && self.turn_number == other.turn_number
+            && self.turn_start == other.turn_start
+            && self.turn_subdivisions == other.turn_subdivisions
+            && self.monotonic == other.monotonic
+            && self.calendar_cycle == other.calendar_cycle
+            && self.local_offset == other.local_offset
+            && self.speed_sample_window == other.speed_sample_window
+            && self.timeline == other.timeline
+            && self.scheduled_events == other.scheduled_events
+            && self.next_event_handle == other.next_event_handle
+    }
+}
+
+impl std::fmt::Debug for SimulationClock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("SimulationDateTime")
-            .field("year", &self.year)
-            .field("month", &self.month)
-            .field("week", &self.week)
-            .field("month", &self.month)
-            .field("day", &self.day)
-            .field("hour", &self.hour)
-            .field("minute", &self.minute)
-            .field("second", &self.second)
+        f.debug_struct("SimulationClock")
+            .field("clock_epoch_seconds", &self.current_epoch_seconds())
+            .field("clock_timestamp", &self.current_timestamp())
+            .field("clock_datetime", &self.current_datetime())
+            .field("clock_speed", &self.clock_speed)
+            .field("clock_speed_ratio", &self.clock_speed_ratio)
+            .field("clock_state", &self.clock_mode)
+            .field("turn_time", &self.turn_duration)
+            .field("turn_remaining", &self.turn_time_remaining)
+            .field("turn_stalled", &self.turn_stall_time)
+            .field("turn_grace", &self.turn_grace)
+            .field("turn_number", &self.turn_number)
+            .field("calendar_cycle", &self.calendar_cycle)
+            .field("local_offset", &self.local_offset)
+            .field("monotonic", &self.monotonic)
             .finish()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::SimulationClock;
-    use std::time::Duration;
-    use tracing_test::traced_test;
+/// Fixed Timestamp
+///
+/// Stored as signed nanoseconds since the Simulation Epoch, so times before the epoch are
+/// represented by negative values. The representable range runs from [`MIN`](Self::MIN) at
+/// `i64::MIN` epoch seconds to [`MAX`](Self::MAX), the last nanosecond of `i64::MAX` epoch
+/// seconds, roughly 92 billion Metric Years either side of the epoch. Constructors and
+/// arithmetic past either end saturate there rather than panicking, except for the `checked_`
+/// and `try_` forms which report the overflow.
+#[derive(Copy, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[serde(from = "EpochTime", into = "EpochTime")]
+pub struct SimulationTimestamp(i128);
 
-    #[test]
-    #[traced_test]
-    fn clock_test() {
-        let mut clock = SimulationClock::default();
-        let mut seconds_since_startup = Duration::default();
-        let mut seconds_since_update = Duration::default();
-        while seconds_since_startup.as_secs_f64() < 20.0 {
-            seconds_since_startup += Duration::from_secs_f64(1.0 / 60.0);
-            seconds_since_update += Duration::from_secs_f64(1.0 / 60.0);
-            clock.tick(seconds_since_update);
-        }
-        clock.enable_turn_mode();
-        while seconds_since_startup.as_secs_f64() < 40.0 {
-            seconds_since_startup += Duration::from_secs_f64(1.0 / 60.0);
-            seconds_since_update += Duration::from_secs_f64(1.0 / 60.0);
-            clock.tick(seconds_since_update);
-            if clock.turn_complete() {
-                clock.advance_turn();
-            }
+/// Serialized form of a Timestamp, laid out like a serialized `Duration` so saves written before
+/// pre-epoch support still load
+#[derive(Serialize, Deserialize)]
+struct EpochTime {
+    /// Whole epoch seconds, rounded towards negative infinity
+    secs: i64,
+    /// Nanoseconds past `secs`
+    nanos: u32,
+}
+
+impl From<EpochTime> for SimulationTimestamp {
+    fn from(time: EpochTime) -> Self {
+        SimulationTimestamp::from_nanos(time.secs as i128 * NANOS_PER_SECOND + time.nanos as i128)
+    }
+}
+
+impl From<SimulationTimestamp> for EpochTime {
+    fn from(timestamp: SimulationTimestamp) -> Self {
+        EpochTime {
+            secs: timestamp.epoch_seconds(),
+            nanos: timestamp.0.rem_euclid(NANOS_PER_SECOND) as u32,
         }
-        clock.disable_turn_mode();
-        while seconds_since_startup.as_secs_f64() < 60.0 {
-            seconds_since_startup += Duration::from_secs_f64(1.0 / 60.0);
-            seconds_since_update += Duration::from_secs_f64(1.0 / 60.0);
-            clock.tick(seconds_since_update);
+    }
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const MIN_TIMESTAMP_NANOS: i128 = i64::MIN as i128 * NANOS_PER_SECOND;
+const MAX_TIMESTAMP_NANOS: i128 = i64::MAX as i128 * NANOS_PER_SECOND + (NANOS_PER_SECOND - 1);
+
+impl SimulationTimestamp {
+    /// Earliest representable Timestamp, `i64::MIN` seconds before the epoch
+    pub const MIN: SimulationTimestamp = SimulationTimestamp(MIN_TIMESTAMP_NANOS);
+    /// Latest representable Timestamp, just short of `i64::MAX + 1` seconds after the epoch
+    pub const MAX: SimulationTimestamp = SimulationTimestamp(MAX_TIMESTAMP_NANOS);
+    /// The Simulation Epoch, Date Time `0-00-00-00@00:00:00`
+    pub const EPOCH: SimulationTimestamp = SimulationTimestamp(0);
+
+    /// Create a Timestamp `epoch_seconds` after the epoch, saturating at [`MAX`](Self::MAX).
+    pub const fn from_epoch_seconds(epoch_seconds: u64) -> SimulationTimestamp {
+        Self::from_nanos(epoch_seconds as i128 * NANOS_PER_SECOND)
+    }
+    /// Create a Timestamp `epoch_seconds` from the epoch, before it when negative.
+    pub const fn from_signed_epoch_seconds(epoch_seconds: i64) -> SimulationTimestamp {
+        Self(epoch_seconds as i128 * NANOS_PER_SECOND)
+    }
+    /// Timestamp `nanos` from the epoch, clamped into the representable range.
+    const fn from_nanos(nanos: i128) -> SimulationTimestamp {
+        if nanos < MIN_TIMESTAMP_NANOS {
+            Self::MIN
+        } else if nanos > MAX_TIMESTAMP_NANOS {
+            Self::MAX
+        } else {
+            Self(nanos)
         }
     }
+    /// Timestamp `nanos` from the epoch, or `None` outside the representable range.
+    fn checked_from_nanos(nanos: i128) -> Option<SimulationTimestamp> {
+        (MIN_TIMESTAMP_NANOS..=MAX_TIMESTAMP_NANOS)
+            .contains(&nanos)
+            .then_some(Self(nanos))
+    }
+    /// Create a Timestamp from Metric components using exact integer arithmetic, saturating at
+    /// [`MIN`](Self::MIN) and [`MAX`](Self::MAX).
+    pub fn from_components(
+        year: i64,
+        month: u8,
+        week: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> SimulationTimestamp {
+        let within_year = checked_epoch_seconds([
+            0,
+            month as u64,
+            week as u64,
+            day as u64,
+            hour as u64,
+            minute as u64,
+            second as u64,
+        ])
+        .unwrap_or(u64::MAX);
+        let epoch_seconds = year as i128 * SECONDS_PER_METRIC_YEAR as i128 + within_year as i128;
+        Self::from_nanos(epoch_seconds * NANOS_PER_SECOND)
+    }
+    /// Create a Timestamp from Metric components, failing with `MetricError::Overflow` where
+    /// `from_components` would saturate.
+    ///
+    /// A negative `year` counts back from the epoch while the remaining components still count
+    /// forwards, so year `-1` month `9` is one Metric Month before the epoch.
+    pub fn try_from_components(
+        year: i64,
+        month: u8,
+        week: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<SimulationTimestamp, MetricError> {
+        let within_year = checked_epoch_seconds([
+            0,
+            month as u64,
+            week as u64,
+            day as u64,
+            hour as u64,
+            minute as u64,
+            second as u64,
+        ])
+        .ok_or(MetricError::Overflow)?;
+        // Summed in i128 so that the earliest years, whose start lies before `i64::MIN`
+        // seconds, still reach times later in the year that fit.
+        let epoch_seconds = year as i128 * SECONDS_PER_METRIC_YEAR as i128 + within_year as i128;
+        i64::try_from(epoch_seconds)
+            .map(SimulationTimestamp::from_signed_epoch_seconds)
+            .map_err(|_| MetricError::Overflow)
+    }
+    /// Checked conversion from a Date Time, failing with `MetricError::OutOfRange` for components
+    /// outside their Metric range and `MetricError::Overflow` if the epoch seconds do not fit.
+    ///
+    /// This is an inherent method because the infallible `From` already provides `TryFrom`.
+    pub fn try_from_datetime(
+        datetime: &SimulationDateTime,
+    ) -> Result<SimulationTimestamp, MetricError> {
+        datetime.validate()?;
+        SimulationTimestamp::try_from_components(
+            datetime.year,
+            datetime.month,
+            datetime.week,
+            datetime.day,
+            datetime.hour,
+            datetime.minute,
+            datetime.second,
+        )
+    }
+    /// Whole seconds since the epoch, rounded towards negative infinity so that times before the
+    /// epoch fall in the second that contains them.
+    pub const fn epoch_seconds(&self) -> i64 {
+        self.0.div_euclid(NANOS_PER_SECOND) as i64
+    }
+    /// Whether this timestamp is before the Simulation Epoch.
+    pub const fn is_before_epoch(&self) -> bool {
+        self.0 < 0
+    }
+    /// Metric Year of this timestamp, as `SimulationDateTime::from(timestamp).year`.
+    pub fn year(&self) -> i64 {
+        self.epoch_seconds()
+            .div_euclid(SECONDS_PER_METRIC_YEAR as i64)
+    }
+    /// Metric Month within the year, from 0 to 9.
+    pub fn month(&self) -> u8 {
+        self.unit_within(SECONDS_PER_METRIC_MONTH, SECONDS_PER_METRIC_YEAR) as u8
+    }
+    /// Metric Week within the month, from 0 to 9.
+    pub fn week(&self) -> u8 {
+        self.unit_within(SECONDS_PER_METRIC_WEEK, SECONDS_PER_METRIC_MONTH) as u8
+    }
+    /// Metric Day within the week, from 0 to 9.
+    pub fn day(&self) -> u8 {
+        self.unit_within(SECONDS_PER_METRIC_DAY, SECONDS_PER_METRIC_WEEK) as u8
+    }
+    /// Metric Hour within the day, from 0 to 9.
+    pub fn hour(&self) -> u8 {
+        self.unit_within(SECONDS_PER_METRIC_HOUR, SECONDS_PER_METRIC_DAY) as u8
+    }
+    /// Metric Minute within the hour, from 0 to 99.
+    pub fn minute(&self) -> u8 {
+        self.unit_within(SECONDS_PER_METRIC_MINUTE, SECONDS_PER_METRIC_HOUR) as u8
+    }
+    /// Metric Second within the minute, from 0 to 99.
+    pub fn second(&self) -> u8 {
+        self.unit_within(1, SECONDS_PER_METRIC_MINUTE) as u8
+    }
+    /// Metric Day within the year, from 0 to 999.
+    pub fn day_of_metric_year(&self) -> u16 {
+        self.unit_within(SECONDS_PER_METRIC_DAY, SECONDS_PER_METRIC_YEAR) as u16
+    }
+    /// Metric Week within the year, from 0 to 99.
+    pub fn week_of_metric_year(&self) -> u16 {
+        self.unit_within(SECONDS_PER_METRIC_WEEK, SECONDS_PER_METRIC_YEAR) as u16
+    }
+    /// Metric Second within the day, from 0 to 99,999.
+    pub fn second_of_metric_day(&self) -> u32 {
+        self.unit_within(1, SECONDS_PER_METRIC_DAY) as u32
+    }
+    /// Whole `unit`s elapsed since the start of the enclosing `period`.
+    fn unit_within(&self, unit: u64, period: u64) -> u64 {
+        self.epoch_seconds().rem_euclid(period as i64) as u64 / unit
+    }
+    /// Create a Timestamp `amount` of the `unit` Metric Unit from the epoch, before it when
+    /// negative.
+    ///
+    /// The nanosecond count is computed in `f64` and rounded to the nearest nanosecond, with
+    /// halves rounding away from zero. It saturates at [`MIN`](Self::MIN) and
+    /// [`MAX`](Self::MAX), and a NaN `amount` gives the epoch.
+    ///
+    /// `f64` holds 53 bits of precision, so results are exact to the nanosecond only up to
+    /// about 90 Metric Days from the epoch. Further out the error grows with distance, to at
+    /// most 128 nanoseconds around Metric Year 12 and about a millisecond by Metric Year 100,000.
+    pub fn from_metric_units_f64(amount: f64, unit: MetricUnit) -> SimulationTimestamp {
+        let nanos = amount * unit.seconds_per_unit() as f64 * NANOS_PER_SECOND as f64;
+        Self::from_nanos(nanos.round() as i128)
+    }
+    /// Create a Timestamp `millennia` Metric Millennia from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_millennia(millennia: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(millennia, MetricUnit::Millennium)
+    }
+    /// Create a Timestamp `centuries` Metric Centuries from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_centuries(centuries: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(centuries, MetricUnit::Century)
+    }
+    /// Create a Timestamp `decades` Metric Decades from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_decades(decades: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(decades, MetricUnit::Decade)
+    }
+    /// Create a Timestamp `years` Metric Years from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_years(years: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(years, MetricUnit::Year)
+    }
+    /// Create a Timestamp `months` Metric Months from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_months(months: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(months, MetricUnit::Month)
+    }
+    /// Create a Timestamp `weeks` Metric Weeks from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_weeks(weeks: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(weeks, MetricUnit::Week)
+    }
+    /// Create a Timestamp `days` Metric Days from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_days(days: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(days, MetricUnit::Day)
+    }
+    /// Create a Timestamp `hours` Metric Hours from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_hours(hours: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(hours, MetricUnit::Hour)
+    }
+    /// Create a Timestamp `minutes` Metric Minutes from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_minutes(minutes: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(minutes, MetricUnit::Minute)
+    }
+    /// Create a Timestamp `seconds` Metric Seconds from the epoch, rounded as
+    /// `from_metric_units_f64`.
+    pub fn from_metric_seconds(seconds: f64) -> SimulationTimestamp {
+        Self::from_metric_units_f64(seconds, MetricUnit::Second)
+    }
+    /// Same as `from_metric_days`.
+    pub fn from_metric_days_f64(days: f64) -> SimulationTimestamp {
+        Self::from_metric_days(days)
+    }
+    /// Same as `from_metric_years`.
+    pub fn from_metric_years_f64(years: f64) -> SimulationTimestamp {
+        Self::from_metric_years(years)
+    }
+    /// Time since the epoch in `unit` Metric Units including the fraction, negative before the
+    /// epoch.
+    pub fn as_metric_units_f64(&self, unit: MetricUnit) -> f64 {
+        self.0 as f64 / (unit.seconds_per_unit() as f64 * NANOS_PER_SECOND as f64)
+    }
+    /// Metric Hours since the epoch including the fraction.
+    pub fn as_metric_hours_f64(&self) -> f64 {
+        self.as_metric_units_f64(MetricUnit::Hour)
+    }
+    /// Metric Days since the epoch including the fraction.
+    pub fn as_metric_days_f64(&self) -> f64 {
+        self.as_metric_units_f64(MetricUnit::Day)
+    }
+    /// Metric Years since the epoch including the fraction.
+    pub fn as_metric_years_f64(&self) -> f64 {
+        self.as_metric_units_f64(MetricUnit::Year)
+    }
+    /// Seconds since the epoch including the fraction, negative before the epoch.
+    pub(crate) fn as_secs_f64(&self) -> f64 {
+        self.0 as f64 / NANOS_PER_SECOND as f64
+    }
+    /// Nanoseconds since the epoch, negative before the epoch.
+    ///
+    /// Every Timestamp converts exactly, and `from_epoch_nanos` turns the result back into the
+    /// same Timestamp.
+    pub const fn as_epoch_nanos(&self) -> i128 {
+        self.0
+    }
+    /// Create a Timestamp `nanos` from the epoch, saturating at [`MIN`](Self::MIN) and
+    /// [`MAX`](Self::MAX).
+    pub const fn from_epoch_nanos(nanos: i128) -> SimulationTimestamp {
+        Self::from_nanos(nanos)
+    }
+    /// Create a Timestamp `nanos` after the epoch, saturating at [`MAX`](Self::MAX).
+    pub const fn from_epoch_nanos_u128(nanos: u128) -> SimulationTimestamp {
+        if nanos > MAX_TIMESTAMP_NANOS as u128 {
+            Self::MAX
+        } else {
+            Self(nanos as i128)
+        }
+    }
+    /// Whole seconds since the epoch, or `None` before the epoch.
+    pub fn as_epoch_seconds_u64(&self) -> Option<u64> {
+        u64::try_from(self.epoch_seconds()).ok()
+    }
+    /// Nanoseconds since the epoch, or `None` before the epoch.
+    pub fn as_epoch_nanos_u128(&self) -> Option<u128> {
+        u128::try_from(self.0).ok()
+    }
+    /// Timestamp `duration` later, or `None` on overflow.
+    pub fn checked_add(&self, duration: Duration) -> Option<SimulationTimestamp> {
+        Self::checked_from_nanos(self.0 + duration.as_nanos() as i128)
+    }
+    /// Timestamp `duration` earlier, or `None` on overflow.
+    pub fn checked_sub(&self, duration: Duration) -> Option<SimulationTimestamp> {
+        Self::checked_from_nanos(self.0 - duration.as_nanos() as i128)
+    }
+    /// Timestamp `duration` later, saturating at the latest representable timestamp.
+    pub fn saturating_add(&self, duration: Duration) -> SimulationTimestamp {
+        Self::from_nanos(self.0 + duration.as_nanos() as i128)
+    }
+    /// Timestamp `duration` earlier, saturating at the earliest representable timestamp.
+    pub fn saturating_sub(&self, duration: Duration) -> SimulationTimestamp {
+        Self::from_nanos(self.0 - duration.as_nanos() as i128)
+    }
+    /// Timestamp shifted by `offset` in either direction, saturating at both ends.
+    pub(crate) fn saturating_add_signed(&self, offset: SignedDuration) -> SimulationTimestamp {
+        if offset.is_negative() {
+            self.saturating_sub(offset.magnitude())
+        } else {
+            self.saturating_add(offset.magnitude())
+        }
+    }
+    /// Timestamp with its time since the epoch multiplied by `speed`, or `None` if the result
+    /// does not fit or `speed` is NaN or negative.
+    pub fn checked_mul_speed(&self, speed: f64) -> Option<SimulationTimestamp> {
+        if speed.is_nan() || speed < 0.0 {
+            return None;
+        }
+        let nanos = self.0 as f64 * speed;
+        if !(MIN_TIMESTAMP_NANOS as f64..=MAX_TIMESTAMP_NANOS as f64).contains(&nanos) {
+            return None;
+        }
+        Some(Self::from_nanos(nanos as i128))
+    }
+    /// Simulation Time elapsed from `earlier` to this timestamp, or zero if `earlier` is in fact
+    /// later.
+    pub fn duration_since(&self, earlier: &SimulationTimestamp) -> SimulationDuration {
+        SimulationDuration::new(self.signed_duration_since(earlier).apply_to(Duration::ZERO))
+    }
+    /// Simulation Time elapsed since `earlier` broken down into Metric Units, or all zero if
+    /// `earlier` is in fact later.
+    pub fn elapsed_since(&self, earlier: &SimulationTimestamp) -> MetricElapsed {
+        self.duration_since(earlier).components()
+    }
+    /// Signed span from `other` to this timestamp, negative when this timestamp is before `other`.
+    pub fn signed_duration_since(&self, other: &SimulationTimestamp) -> SignedDuration {
+        let magnitude = duration_from_nanos(self.0.abs_diff(other.0)).unwrap_or(Duration::MAX);
+        if self.0 < other.0 {
+            SignedDuration::negative(magnitude)
+        } else {
+            SignedDuration::positive(magnitude)
+        }
+    }
+    /// Whether both timestamps fall within the same `scale` unit.
+    pub fn same_unit(&self, other: &SimulationTimestamp, scale: MetricScale) -> bool {
+        let unit = scale.seconds_per_unit() as i64;
+        self.epoch_seconds().div_euclid(unit) == other.epoch_seconds().div_euclid(unit)
+    }
+    /// Fixed width key whose lexical ordering matches chronological ordering.
+    ///
+    /// The key is 20 digits of epoch seconds followed by 9 digits of nanoseconds. Times before
+    /// the epoch replace the first digit with `-` and count their seconds up from `i64::MIN`, so
+    /// they sort ahead of every time after it.
+    pub fn to_sortable_key(&self) -> String {
+        let time = EpochTime::from(*self);
+        match u64::try_from(time.secs) {
+            Ok(secs) => format!("{:020}{:09}", secs, time.nanos),
+            Err(_) => format!("-{:019}{:09}", time.secs.abs_diff(i64::MIN), time.nanos),
+        }
+    }
+    /// Parse a key produced by `to_sortable_key`.
+    pub fn from_sortable_key(key: &str) -> Result<SimulationTimestamp, MetricError> {
+        let parse_error = || MetricError::Parse(key.to_string());
+        let digits = key.strip_prefix('-').unwrap_or(key);
+        if key.len() != 29 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(parse_error());
+        }
+        let seconds = digits[..digits.len() - 9]
+            .parse::<i64>()
+            .map_err(|_| parse_error())?;
+        let seconds = if digits.len() < key.len() {
+            i64::MIN + seconds
+        } else {
+            seconds
+        };
+        let nanos = key[20..].parse::<u32>().map_err(|_| parse_error())?;
+        Ok(Self::from(EpochTime {
+            secs: seconds,
+            nanos,
+        }))
+    }
+    /// Round down to the nearest multiple of `grid` since the epoch, saturating at the earliest
+    /// representable timestamp. A zero `grid` leaves the timestamp unchanged.
+    ///
+    /// `grid` is either a `Duration` or a [`MetricUnit`], so `floor_to(MetricUnit::Day)` gives
+    /// the start of the current Metric Day.
+    pub fn floor_to(&self, grid: impl Into<Duration>) -> SimulationTimestamp {
+        let grid = grid.into();
+        if grid.is_zero() {
+            return *self;
+        }
+        let floored = self.0 - self.0.rem_euclid(grid.as_nanos() as i128);
+        Self::from_nanos(floored)
+    }
+    /// Round up to the nearest multiple of `grid` since the epoch, saturating at the largest
+    /// representable timestamp. A zero `grid` leaves the timestamp unchanged.
+    pub fn ceil_to(&self, grid: impl Into<Duration>) -> SimulationTimestamp {
+        let grid = grid.into();
+        if grid.is_zero() {
+            return *self;
+        }
+        let grid = grid.as_nanos() as i128;
+        let ceiled = match self.0.rem_euclid(grid) {
+            0 => self.0,
+            remainder => self.0 + (grid - remainder),
+        };
+        Self::from_nanos(ceiled)
+    }
+    /// Every multiple of `step` since the epoch from this timestamp up to but excluding `end`,
+    /// in chronological order.
+    ///
+    /// `step` is either a `Duration` or a [`MetricUnit`], so `iter_to(end, MetricUnit::Day)`
+    /// yields the start of each Metric Day in the range. A zero `step` or an `end` at or before
+    /// this timestamp yields nothing.
+    ///
+    /// ```rust
+    /// use metriclock::{MetricUnit, SimulationTimestamp};
+    ///
+    /// let start = SimulationTimestamp::from_epoch_seconds(150_000);
+    /// let end = SimulationTimestamp::from_epoch_seconds(400_000);
+    /// let days: Vec<i64> = start
+    ///     .iter_to(end, MetricUnit::Day)
+    ///     .map(|day| day.epoch_seconds())
+    ///     .collect();
+    /// assert_eq!(days, [200_000, 300_000]);
+    /// ```
+    pub fn iter_to(
+        &self,
+        end: SimulationTimestamp,
+        step: impl Into<Duration>,
+    ) -> impl Iterator<Item = SimulationTimestamp> {
+        let step = step.into();
+        let first = (!step.is_zero()).then(|| self.ceil_to(step));
+        std::iter::successors(first, move |boundary| boundary.checked_add(step))
+            .take_while(move |boundary| *boundary < end)
+    }
+    /// Round to the nearest multiple of `grid` since the epoch, with halves rounding up. A zero
+    /// `grid` leaves the timestamp unchanged.
+    pub fn round_to(&self, grid: impl Into<Duration>) -> SimulationTimestamp {
+        let grid = grid.into();
+        if grid.is_zero() {
+            return *self;
+        }
+        let remainder = self.0.rem_euclid(grid.as_nanos() as i128);
+        if remainder * 2 >= grid.as_nanos() as i128 {
+            self.ceil_to(grid)
+        } else {
+            self.floor_to(grid)
+        }
+    }
+}
+
+/// Durations past the latest representable timestamp saturate there.
+impl From<Duration> for SimulationTimestamp {
+    fn from(duration: Duration) -> Self {
+        SimulationTimestamp::from_nanos(duration.as_nanos() as i128)
+    }
+}
+
+/// Shifting a Timestamp saturates at the ends of the representable range rather than panicking.
+impl std::ops::Add<Duration> for SimulationTimestamp {
+    type Output = SimulationTimestamp;
+    fn add(self, rhs: Duration) -> SimulationTimestamp {
+        self.saturating_add(rhs)
+    }
+}
+
+impl std::ops::Sub<Duration> for SimulationTimestamp {
+    type Output = SimulationTimestamp;
+    fn sub(self, rhs: Duration) -> SimulationTimestamp {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl std::ops::AddAssign<Duration> for SimulationTimestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = self.saturating_add(rhs);
+    }
+}
+
+impl std::ops::SubAssign<Duration> for SimulationTimestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = self.saturating_sub(rhs);
+    }
+}
+
+impl std::ops::Add<SimulationDuration> for SimulationTimestamp {
+    type Output = SimulationTimestamp;
+    fn add(self, rhs: SimulationDuration) -> SimulationTimestamp {
+        self + rhs.as_duration()
+    }
+}
+
+impl std::ops::Sub<SimulationDuration> for SimulationTimestamp {
+    type Output = SimulationTimestamp;
+    fn sub(self, rhs: SimulationDuration) -> SimulationTimestamp {
+        self - rhs.as_duration()
+    }
+}
+
+impl std::ops::AddAssign<SimulationDuration> for SimulationTimestamp {
+    fn add_assign(&mut self, rhs: SimulationDuration) {
+        *self += rhs.as_duration();
+    }
+}
+
+impl std::ops::SubAssign<SimulationDuration> for SimulationTimestamp {
+    fn sub_assign(&mut self, rhs: SimulationDuration) {
+        *self -= rhs.as_duration();
+    }
+}
+
+/// Components are not range checked, so an invalid Date Time such as month 12 carries into the
+/// next year; use `SimulationTimestamp::try_from_datetime` to reject those. Years too large for
+/// `i64` epoch seconds saturate at the ends of the representable range.
+impl From<SimulationDateTime> for SimulationTimestamp {
+    fn from(datetime: SimulationDateTime) -> Self {
+        datetime.timestamp()
+    }
+}
+
+impl std::fmt::Debug for SimulationTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimulationTimestamp")
+            .field("epoch_seconds", &self.epoch_seconds())
+            .finish()
+    }
+}
+
+/// Exact epoch seconds with any fraction written without trailing zeros, such as `-12.5`.
+impl std::fmt::Display for SimulationTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let nanos = self.0.unsigned_abs();
+        let seconds = nanos / NANOS_PER_SECOND as u128;
+        match nanos % NANOS_PER_SECOND as u128 {
+            0 => write!(f, "{}{}", sign, seconds),
+            fraction => {
+                let fraction = format!("{:09}", fraction);
+                write!(f, "{}{}.{}", sign, seconds, fraction.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+/// Parse either the `Display` form of epoch seconds, such as `-12.5`, or a Date Time string
+/// such as `12-03-04-05@06:07:08`.
+///
+/// Epoch seconds may carry up to nine fractional digits. Malformed input fails with
+/// `MetricError::Parse`, a Date Time component outside its Metric range with
+/// `MetricError::OutOfRange`, and a time outside the representable range with
+/// `MetricError::Overflow`.
+impl std::str::FromStr for SimulationTimestamp {
+    type Err = MetricError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.contains('@') {
+            let datetime: SimulationDateTime = input.parse()?;
+            return SimulationTimestamp::try_from_datetime(&datetime)?
+                .checked_add(Duration::from_nanos(datetime.nanosecond as u64))
+                .ok_or(MetricError::Overflow);
+        }
+        let parse_error = || MetricError::Parse(input.to_string());
+        let (negative, unsigned) = match input.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, input),
+        };
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) if (1..=9).contains(&fraction.len()) => (whole, fraction),
+            Some(_) => return Err(parse_error()),
+            None => (unsigned, "0"),
+        };
+        let seconds: u64 = parse_component(whole).ok_or_else(parse_error)?;
+        let scale = 10i128.pow(9 - fraction.len() as u32);
+        let fraction: u32 = parse_component(fraction).ok_or_else(parse_error)?;
+        let nanos = seconds as i128 * NANOS_PER_SECOND + fraction as i128 * scale;
+        let nanos = if negative { -nanos } else { nanos };
+        SimulationTimestamp::checked_from_nanos(nanos).ok_or(MetricError::Overflow)
+    }
+}
+
+/// Data Time of the Simulation
+///
+/// Ordering compares fields from most to least significant, which agrees with comparing
+/// `to_epoch_seconds` for validated Date Times.
+///
+/// Deserialization rejects components outside of their Metric range.
+///
+/// Date Times before the epoch have a negative `year` while every other component still counts
+/// forwards from the start of that year, so one Metric Second before the epoch is year `-1`,
+/// `9-09-09@09:99:99`.
+///
+/// `nanosecond` holds the fraction of the current Metric Second, from 0 to 999,999,999.
+#[derive(Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(try_from = "SimulationDateTimeFields")]
+pub struct SimulationDateTime {
+    pub year: i64,
+    pub month: u8,
+    pub week: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub nanosecond: u32,
+}
+
+impl SimulationDateTime {
+    pub fn from_epoch_seconds(epoch_seconds: u64) -> Self {
+        let mut epoch_seconds = epoch_seconds;
+        let year = epoch_seconds / 100_000_000;
+        epoch_seconds -= year * 100_000_000;
+        let month = epoch_seconds / 10_000_000;
+        epoch_seconds -= month * 10_000_000;
+        let week = epoch_seconds / 1_000_000;
+        epoch_seconds -= week * 1_000_000;
+        let day = epoch_seconds / 100_000;
+        epoch_seconds -= day * 100_000;
+        let hour = epoch_seconds / 10_000;
+        epoch_seconds -= hour * 10_000;
+        let minute = epoch_seconds / 100;
+        epoch_seconds -= minute * 100;
+        let second = epoch_seconds;
+        Self {
+            year: year as i64,
+            month: month as u8,
+            week: week as u8,
+            day: day as u8,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond: 0,
+        }
+    }
+    /// Decompose `epoch_seconds` from the epoch, counting back from it when negative.
+    pub fn from_signed_epoch_seconds(epoch_seconds: i64) -> Self {
+        let year = epoch_seconds.div_euclid(SECONDS_PER_METRIC_YEAR as i64);
+        let within_year = epoch_seconds.rem_euclid(SECONDS_PER_METRIC_YEAR as i64);
+        SimulationDateTime {
+            year,
+            ..SimulationDateTime::from_epoch_seconds(within_year as u64)
+        }
+    }
+    /// Decompose `duration` applying `mode` to its fractional seconds, leaving `nanosecond` zero.
+    ///
+    /// Rounding up carries into larger units, so 99.6 seconds rounds to the next Metric Minute.
+    pub fn from_duration_rounded(duration: Duration, mode: RoundingMode) -> Self {
+        let epoch_seconds = match mode {
+            RoundingMode::Truncate => duration.as_secs(),
+            RoundingMode::Nearest if duration.subsec_nanos() >= 500_000_000 => {
+                duration.as_secs().saturating_add(1)
+            }
+            RoundingMode::Nearest => duration.as_secs(),
+        };
+        SimulationDateTime::from_epoch_seconds(epoch_seconds)
+    }
+    pub fn from_components(
+        year: i64,
+        month: u8,
+        week: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> SimulationDateTime {
+        SimulationDateTime {
+            year,
+            month,
+            week,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond: 0,
+        }
+    }
+    /// Start building a Date Time from individual components, all zero until set.
+    ///
+    /// ```rust
+    /// use metriclock::SimulationDateTime;
+    ///
+    /// let (datetime, timestamp) = SimulationDateTime::builder().hour(3).minute(150).build();
+    /// assert_eq!(datetime, SimulationDateTime::from_components(0, 0, 0, 0, 4, 50, 0));
+    /// assert_eq!(timestamp.epoch_seconds(), 45_000);
+    /// ```
+    pub fn builder() -> SimulationDateTimeBuilder {
+        SimulationDateTimeBuilder::default()
+    }
+    /// Create a Date Time from components, failing with `MetricError::OutOfRange` for the first
+    /// component outside its Metric range.
+    pub fn try_from_components(
+        year: i64,
+        month: u8,
+        week: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<SimulationDateTime, MetricError> {
+        let datetime =
+            SimulationDateTime::from_components(year, month, week, day, hour, minute, second);
+        datetime.validate()?;
+        Ok(datetime)
+    }
+    /// Create a Date Time from components, carrying any overflow into larger units.
+    ///
+    /// For example 150 Metric Seconds becomes 1 Metric Minute and 50 Metric Seconds. Years that
+    /// overflow `i64` epoch seconds saturate like `to_epoch_seconds`.
+    pub fn from_components_normalized(
+        year: i64,
+        month: u8,
+        week: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> SimulationDateTime {
+        let unnormalized =
+            SimulationDateTime::from_components(year, month, week, day, hour, minute, second);
+        SimulationDateTime::from_signed_epoch_seconds(unnormalized.to_epoch_seconds())
+    }
+    /// Metric Day within the year, from 0 to 999.
+    pub fn day_of_metric_year(&self) -> u16 {
+        self.month as u16 * 100 + self.week as u16 * 10 + self.day as u16
+    }
+    /// Metric Week within the year, from 0 to 99.
+    ///
+    /// Unnormalized components, as `from_components` accepts, count on past 99 instead of
+    /// overflowing.
+    pub fn week_of_metric_year(&self) -> u16 {
+        self.month as u16 * 10 + self.week as u16
+    }
+    /// Metric Second within the day, from 0 to 99,999.
+    pub fn second_of_metric_day(&self) -> u32 {
+        self.hour as u32 * 10_000 + self.minute as u32 * 100 + self.second as u32
+    }
+    /// Metric Millennium containing this Date Time, negative before the epoch.
+    pub fn millennium(&self) -> i64 {
+        self.year.div_euclid(1_000)
+    }
+    /// Metric Century within the current Metric Millennium, from 0 to 9.
+    pub fn century(&self) -> u8 {
+        self.year.div_euclid(100).rem_euclid(10) as u8
+    }
+    /// Metric Decade within the current Metric Century, from 0 to 9.
+    pub fn decade(&self) -> u8 {
+        self.year.div_euclid(10).rem_euclid(10) as u8
+    }
+    /// Metric Year within the current Metric Decade, from 0 to 9.
+    pub fn year_of_decade(&self) -> u8 {
+        self.year.rem_euclid(10) as u8
+    }
+    /// Long horizon rendering such as `"Millennium 1, Century 3, Decade 7, Year 2"`.
+    pub fn to_era_string(&self) -> String {
+        format!(
+            "Millennium {}, Century {}, Decade {}, Year {}",
+            self.millennium(),
+            self.century(),
+            self.decade(),
+            self.year_of_decade()
+        )
+    }
+    /// Components ordered from year to second, for destructuring in patterns.
+    pub fn as_tuple(&self) -> (i64, u8, u8, u8, u8, u8, u8) {
+        (
+            self.year,
+            self.month,
+            self.week,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        )
+    }
+    /// Create a Date Time from components ordered as returned by `as_tuple`.
+    pub fn from_tuple(
+        (year, month, week, day, hour, minute, second): (i64, u8, u8, u8, u8, u8, u8),
+    ) -> SimulationDateTime {
+        SimulationDateTime::from_components(year, month, week, day, hour, minute, second)
+    }
+    /// Add `duration` to this Date Time, clamping at the largest Date Time whose epoch seconds
+    /// fit in an `i64`.
+    pub fn saturating_add_duration(&self, duration: Duration) -> SimulationDateTime {
+        SimulationDateTime::from(self.timestamp().saturating_add(duration))
+    }
+    /// Compact rendering such as `"2Y 3Mo 5D 06:07:08"` for HUDs.
+    ///
+    /// | Symbol | Unit          |
+    /// |--------|---------------|
+    /// | `Y`    | Metric Year   |
+    /// | `Mo`   | Metric Month  |
+    /// | `W`    | Metric Week   |
+    /// | `D`    | Metric Day    |
+    /// | `h`    | Metric Hour   |
+    /// | `m`    | Metric Minute |
+    /// | `s`    | Metric Second |
+    ///
+    /// Zero valued date units are omitted, while the clock portion is always rendered as
+    /// `hh:mm:ss`.
+    pub fn to_symbolic_string(&self) -> String {
+        let mut output = String::new();
+        let date_units = [
+            (self.year, "Y"),
+            (self.month as i64, "Mo"),
+            (self.week as i64, "W"),
+            (self.day as i64, "D"),
+        ];
+        for (value, symbol) in date_units {
+            if value != 0 {
+                output.push_str(&format!("{}{} ", value, symbol));
+            }
+        }
+        output.push_str(&format!(
+            "{:02}:{:02}:{:02}",
+            self.hour, self.minute, self.second
+        ));
+        output
+    }
+    /// Every Metric Day of the given Metric Month in chronological order.
+    ///
+    /// Each Metric Month holds 10 Metric Weeks of 10 Metric Days, so this always yields 100 items.
+    pub fn days_in_month(year: i64, month: u8) -> impl Iterator<Item = SimulationDateTime> {
+        (0..10u8).flat_map(move |week| {
+            (0..10u8).map(move |day| {
+                SimulationDateTime::from_components(year, month, week, day, 0, 0, 0)
+            })
+        })
+    }
+    /// Whether this is a rest day in a week of `work_days` working days followed by rest.
+    ///
+    /// Days are numbered from zero, so with 8 work days, days 8 and 9 are rest days. Zero work
+    /// days makes every day a rest day and 10 or more makes none.
+    pub fn is_rest_day(&self, work_days: u8) -> bool {
+        self.day >= work_days
+    }
+    /// Check that every component is within its Metric range.
+    pub fn validate(&self) -> Result<(), MetricError> {
+        if self.nanosecond > 999_999_999 {
+            return Err(MetricError::OutOfRange {
+                component: "nanosecond",
+                value: self.nanosecond as u64,
+                max: 999_999_999,
+            });
+        }
+        let components = [
+            ("month", self.month, 9),
+            ("week", self.week, 9),
+            ("day", self.day, 9),
+            ("hour", self.hour, 9),
+            ("minute", self.minute, 99),
+            ("second", self.second, 99),
+        ];
+        for (component, value, max) in components {
+            if value > max {
+                return Err(MetricError::OutOfRange {
+                    component,
+                    value: value as u64,
+                    max: max as u64,
+                });
+            }
+        }
+        Ok(())
+    }
+    /// Whole seconds since Simulation Epoch represented by this Date Time, negative before the
+    /// epoch. The `nanosecond` fraction is not included.
+    ///
+    /// Years too large for `i64` epoch seconds saturate at `i64::MIN` or `i64::MAX`.
+    pub fn to_epoch_seconds(&self) -> i64 {
+        let within_year = self.month as i64 * SECONDS_PER_METRIC_MONTH as i64
+            + self.week as i64 * SECONDS_PER_METRIC_WEEK as i64
+            + self.day as i64 * SECONDS_PER_METRIC_DAY as i64
+            + self.hour as i64 * SECONDS_PER_METRIC_HOUR as i64
+            + self.minute as i64 * SECONDS_PER_METRIC_MINUTE as i64
+            + self.second as i64;
+        let epoch_seconds =
+            self.year as i128 * SECONDS_PER_METRIC_YEAR as i128 + within_year as i128;
+        epoch_seconds.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+    /// Timestamp of this Date Time including the `nanosecond` fraction.
+    fn timestamp(&self) -> SimulationTimestamp {
+        SimulationTimestamp::from_signed_epoch_seconds(self.to_epoch_seconds())
+            .saturating_add(Duration::from_nanos(self.nanosecond as u64))
+    }
+    /// Simulation Time elapsed since `earlier` broken down into Metric Units, such as an age or
+    /// the time since a founding, or all zero if `earlier` is in fact later.
+    pub fn elapsed_since(&self, earlier: &SimulationDateTime) -> MetricElapsed {
+        self.timestamp().elapsed_since(&earlier.timestamp())
+    }
+    /// Total Metric Weeks elapsed since Simulation Epoch, negative before the epoch.
+    pub fn total_weeks(&self) -> i64 {
+        self.to_epoch_seconds()
+            .div_euclid(SECONDS_PER_METRIC_WEEK as i64)
+    }
+    /// Total Metric Days elapsed since Simulation Epoch, negative before the epoch.
+    pub fn total_days(&self) -> i64 {
+        self.to_epoch_seconds()
+            .div_euclid(SECONDS_PER_METRIC_DAY as i64)
+    }
+    /// Total Metric Hours elapsed since Simulation Epoch, negative before the epoch.
+    pub fn total_hours(&self) -> i64 {
+        self.to_epoch_seconds()
+            .div_euclid(SECONDS_PER_METRIC_HOUR as i64)
+    }
+    /// Fraction of the way through the current `scale` unit, in `[0.0, 1.0)`.
+    pub fn fraction_of(&self, scale: MetricScale) -> f64 {
+        let unit = scale.seconds_per_unit() as i64;
+        let fraction = self.to_epoch_seconds().rem_euclid(unit) as f64 / unit as f64;
+        fraction.clamp(0.0, 1.0 - f64::EPSILON)
+    }
+}
+
+/// Incremental constructor for a [`SimulationDateTime`], created by
+/// [`SimulationDateTime::builder`]
+///
+/// Components may exceed their Metric range and are carried into larger units by `build`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SimulationDateTimeBuilder {
+    year: i64,
+    month: u64,
+    week: u64,
+    day: u64,
+    hour: u64,
+    minute: u64,
+    second: u64,
+}
+
+impl SimulationDateTimeBuilder {
+    pub fn year(mut self, year: i64) -> Self {
+        self.year = year;
+        self
+    }
+    pub fn month(mut self, month: u64) -> Self {
+        self.month = month;
+        self
+    }
+    pub fn week(mut self, week: u64) -> Self {
+        self.week = week;
+        self
+    }
+    pub fn day(mut self, day: u64) -> Self {
+        self.day = day;
+        self
+    }
+    pub fn hour(mut self, hour: u64) -> Self {
+        self.hour = hour;
+        self
+    }
+    pub fn minute(mut self, minute: u64) -> Self {
+        self.minute = minute;
+        self
+    }
+    pub fn second(mut self, second: u64) -> Self {
+        self.second = second;
+        self
+    }
+    /// Normalize the components into a canonical Date Time and its equivalent Timestamp.
+    ///
+    /// Totals beyond `i64` epoch seconds saturate at the largest or smallest Date Time.
+    pub fn build(self) -> (SimulationDateTime, SimulationTimestamp) {
+        let within_year = checked_epoch_seconds([
+            0,
+            self.month,
+            self.week,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        ])
+        .unwrap_or(u64::MAX);
+        let epoch_seconds = (self.year as i128 * SECONDS_PER_METRIC_YEAR as i128
+            + within_year as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        (
+            SimulationDateTime::from_signed_epoch_seconds(epoch_seconds),
+            SimulationTimestamp::from_signed_epoch_seconds(epoch_seconds),
+        )
+    }
+}
+
+/// Rounding applied when decomposing fractional seconds
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Discard fractional seconds
+    #[default]
+    Truncate,
+    /// Round to the nearest second, with halves rounding up
+    Nearest,
+}
+
+/// Unvalidated Date Time components as read from a serialized form
+#[derive(Deserialize)]
+struct SimulationDateTimeFields {
+    year: i64,
+    month: u8,
+    week: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    #[serde(default)]
+    nanosecond: u32,
+}
+
+/// Whether a serialized `nanosecond` can be omitted.
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+impl TryFrom<SimulationDateTimeFields> for SimulationDateTime {
+    type Error = MetricError;
+    fn try_from(fields: SimulationDateTimeFields) -> Result<Self, Self::Error> {
+        let datetime = SimulationDateTime {
+            nanosecond: fields.nanosecond,
+            ..SimulationDateTime::from_components(
+                fields.year,
+                fields.month,
+                fields.week,
+                fields.day,
+                fields.hour,
+                fields.minute,
+                fields.second,
+            )
+        };
+        datetime.validate()?;
+        Ok(datetime)
+    }
+}
+
+impl From<Duration> for SimulationDateTime {
+    fn from(duration: Duration) -> Self {
+        SimulationDateTime {
+            nanosecond: duration.subsec_nanos(),
+            ..SimulationDateTime::from_epoch_seconds(duration.as_secs())
+        }
+    }
+}
+
+impl From<SimulationTimestamp> for SimulationDateTime {
+    fn from(timestamp: SimulationTimestamp) -> Self {
+        SimulationDateTime {
+            nanosecond: timestamp.0.rem_euclid(NANOS_PER_SECOND) as u32,
+            ..SimulationDateTime::from_signed_epoch_seconds(timestamp.epoch_seconds())
+        }
+    }
+}
+
+/// Compact `Y-MM-WW-DD@hh:mm:ss` form, or one labeled component per line with `{:#}`.
+///
+/// Years before the epoch are written with a sign and at least four digits, such as
+/// `-0003-04-05-06@07:08:09`. A non-zero `nanosecond` is written as a decimal fraction of the
+/// second without trailing zeros, such as `12-03-04-05@06:07:08.25`.
+impl std::fmt::Display for SimulationDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return write!(
+                f,
+                "year: {}\nmonth: {}\nweek: {}\nday: {}\nhour: {}\nminute: {}\nsecond: {}",
+                self.year, self.month, self.week, self.day, self.hour, self.minute, self.second
+            )
+            .and_then(|_| match self.nanosecond {
+                0 => Ok(()),
+                nanosecond => write!(f, "\nnanosecond: {}", nanosecond),
+            });
+        }
+        if self.year < 0 {
+            write!(f, "-{:04}", self.year.unsigned_abs())?;
+        } else {
+            write!(f, "{}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}-{:02}@{:02}:{:02}:{:02.4}",
+            &self.month, &self.week, &self.day, &self.hour, &self.minute, &self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse the `Display` form `Y-MM-WW-DD@hh:mm:ss`, such as `12-03-04-05@06:07:08`.
+///
+/// The seconds may carry a decimal fraction, such as `12-03-04-05@06:07:08.25`, which is kept
+/// to nanosecond precision. Digits past the ninth must be zeros, so the `.0000` suffix written
+/// by older releases is accepted as well. Components must be plain decimal digits within their
+/// Metric range, and a leading `-` marks a year before the epoch.
+impl std::str::FromStr for SimulationDateTime {
+    type Err = MetricError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parse_error = || MetricError::Parse(input.to_string());
+        let (date, time) = input.split_once('@').ok_or_else(parse_error)?;
+        let (negative, date) = match date.strip_prefix('-') {
+            Some(date) => (true, date),
+            None => (false, date),
+        };
+        let date: Vec<&str> = date.split('-').collect();
+        let time: Vec<&str> = time.split(':').collect();
+        let [year, month, week, day] = date.as_slice() else {
+            return Err(parse_error());
+        };
+        let [hour, minute, second] = time.as_slice() else {
+            return Err(parse_error());
+        };
+        let (second, nanosecond) = match second.split_once('.') {
+            Some((whole, fraction)) if fraction.bytes().all(|b| b.is_ascii_digit()) => {
+                let (digits, excess) = fraction.split_at(fraction.len().min(9));
+                if excess.bytes().any(|b| b != b'0') {
+                    return Err(parse_error());
+                }
+                let nanos: u32 = parse_component(digits).ok_or_else(parse_error)?;
+                (whole, nanos * 10u32.pow(9 - digits.len() as u32))
+            }
+            Some(_) => return Err(parse_error()),
+            None => (*second, 0),
+        };
+        let year: i64 = parse_component(year).ok_or_else(parse_error)?;
+        let datetime = SimulationDateTime {
+            year: if negative { -year } else { year },
+            month: parse_component(month).ok_or_else(parse_error)?,
+            week: parse_component(week).ok_or_else(parse_error)?,
+            day: parse_component(day).ok_or_else(parse_error)?,
+            hour: parse_component(hour).ok_or_else(parse_error)?,
+            minute: parse_component(minute).ok_or_else(parse_error)?,
+            second: parse_component(second).ok_or_else(parse_error)?,
+            nanosecond,
+        };
+        datetime.validate()?;
+        Ok(datetime)
+    }
+}
+
+/// Parse a Date Time component made only of decimal digits.
+fn parse_component<T: std::str::FromStr>(text: &str) -> Option<T> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+impl std::fmt::Debug for SimulationDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimulationDateTime")
+            .field("year", &self.year)
+            .field("month", &self.month)
+            .field("week", &self.week)
+            .field("day", &self.day)
+            .field("hour", &self.hour)
+            .field("minute", &self.minute)
+            .field("second", &self.second)
+            .field("nanosecond", &self.nanosecond)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[test]
+    #[traced_test]
+    fn clock_test() {
+        let mut clock = SimulationClock::default();
+        let mut seconds_since_startup = Duration::default();
+        let mut seconds_since_update = Duration::default();
+        while seconds_since_startup.as_secs_f64() < 20.0 {
+            seconds_since_startup += Duration::from_secs_f64(1.0 / 60.0);
+            seconds_since_update += Duration::from_secs_f64(1.0 / 60.0);
+            clock.tick(seconds_since_update);
+        }
+        clock.enable_turn_mode();
+        while seconds_since_startup.as_secs_f64() < 40.0 {
+            seconds_since_startup += Duration::from_secs_f64(1.0 / 60.0);
+            seconds_since_update += Duration::from_secs_f64(1.0 / 60.0);
+            clock.tick(seconds_since_update);
+            if clock.turn_complete() {
+                clock.advance_turn();
+            }
+        }
+        clock.disable_turn_mode();
+        while seconds_since_startup.as_secs_f64() < 60.0 {
+            seconds_since_startup += Duration::from_secs_f64(1.0 / 60.0);
+            seconds_since_update += Duration::from_secs_f64(1.0 / 60.0);
+            clock.tick(seconds_since_update);
+        }
+    }
+
+    #[test]
+    fn turn_starvation_test() {
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(6));
+        assert!(clock.turn_complete());
+        assert_eq!(clock.stalled_duration(), Duration::ZERO);
+
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(clock.stalled_duration(), Duration::from_secs(1));
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.stalled_duration(), Duration::from_secs(3));
+        assert!(clock.is_starved(Duration::from_secs(2)));
+        assert!(!clock.is_starved(Duration::from_secs(5)));
+
+        clock.advance_turn();
+        assert_eq!(clock.stalled_duration(), Duration::ZERO);
+        assert!(!clock.is_starved(Duration::ZERO));
+    }
+
+    #[test]
+    fn datetime_totals_test() {
+        let datetime = SimulationDateTime::from_components(2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(datetime.to_epoch_seconds(), 234_560_708);
+        assert_eq!(datetime.total_weeks(), 234);
+        assert_eq!(datetime.total_days(), 2_345);
+        assert_eq!(datetime.total_hours(), 23_456);
+    }
+
+    #[test]
+    fn advance_sim_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed(4.0);
+        clock.advance_sim(Duration::from_secs(10_000));
+        assert_eq!(clock.current_epoch_seconds(), 10_000.0);
+        assert_eq!(clock.current_datetime().hour, 1);
+
+        clock.set_clock_speed(0.0);
+        clock.advance_sim(Duration::from_secs(100));
+        assert_eq!(clock.current_epoch_seconds(), 10_100.0);
+
+        clock.enable_turn_mode();
+        clock.advance_sim(Duration::from_secs(6));
+        assert!(clock.turn_complete());
+        clock.advance_sim(Duration::from_secs(6));
+        assert_eq!(clock.current_epoch_seconds(), 10_106.0);
+    }
+
+    #[test]
+    fn turn_grace_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_turn_grace(Duration::from_millis(500));
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(6));
+        assert!(!clock.turn_complete());
+        clock.tick(Duration::from_millis(250));
+        assert!(!clock.turn_complete());
+        clock.advance_turn();
+        assert_eq!(clock.turn_number(), 0);
+        clock.tick(Duration::from_millis(250));
+        assert!(clock.turn_complete());
+
+        clock.advance_turn();
+        assert_eq!(clock.turn_number(), 1);
+        clock.tick(Duration::from_millis(6_400));
+        assert_eq!(clock.stalled_duration(), Duration::from_millis(400));
+        assert!(!clock.turn_complete());
+        clock.tick(Duration::from_millis(100));
+        assert!(clock.turn_complete());
+
+        clock.advance_turn();
+        clock.tick(Duration::from_millis(6_500));
+        assert!(clock.turn_complete());
+
+        clock.advance_turn();
+        clock.set_turn_grace(Duration::ZERO);
+        clock.tick(Duration::from_secs(6));
+        assert!(clock.turn_complete());
+    }
+
+    #[test]
+    fn sortable_key_test() {
+        let mut timestamps = vec![
+            SimulationTimestamp::from(Duration::new(234_560_708, 0)),
+            SimulationTimestamp::from(Duration::new(9, 500_000_000)),
+            SimulationTimestamp::from(Duration::new(u64::MAX, 999_999_999)),
+            SimulationTimestamp::from(Duration::new(10, 0)),
+            SimulationTimestamp::from(Duration::new(9, 0)),
+        ];
+        let mut keys: Vec<String> = timestamps.iter().map(|t| t.to_sortable_key()).collect();
+        timestamps.sort();
+        keys.sort();
+        assert_eq!(keys.iter().map(|k| k.len()).max(), Some(29));
+        assert_eq!(keys.iter().map(|k| k.len()).min(), Some(29));
+        let parsed: Vec<SimulationTimestamp> = keys
+            .iter()
+            .map(|k| SimulationTimestamp::from_sortable_key(k).unwrap())
+            .collect();
+        assert_eq!(parsed, timestamps);
+
+        assert!(SimulationTimestamp::from_sortable_key("12").is_err());
+        assert!(SimulationTimestamp::from_sortable_key("0000000000000000000a000000000").is_err());
+        assert!(SimulationTimestamp::from_sortable_key("99999999999999999999000000000").is_err());
+    }
+
+    #[test]
+    fn fraction_of_test() {
+        let start = SimulationDateTime::from_components(4, 0, 0, 0, 0, 0, 0);
+        assert_eq!(start.fraction_of(MetricScale::Day), 0.0);
+        assert_eq!(start.fraction_of(MetricScale::Month), 0.0);
+
+        let quarter_day = SimulationDateTime::from_components(4, 2, 5, 0, 2, 50, 0);
+        assert_eq!(quarter_day.fraction_of(MetricScale::Day), 0.25);
+        let quarter_month = SimulationDateTime::from_components(4, 2, 2, 5, 0, 0, 0);
+        assert_eq!(quarter_month.fraction_of(MetricScale::Month), 0.25);
+
+        let end = SimulationDateTime::from_components(4, 9, 9, 9, 9, 99, 99);
+        assert!(end.fraction_of(MetricScale::Day) < 1.0);
+        assert!(end.fraction_of(MetricScale::Day) > 0.9999);
+        assert!(end.fraction_of(MetricScale::Month) > 0.9999);
+        assert_eq!(end.fraction_of(MetricScale::Second), 0.0);
+    }
+
+    #[test]
+    fn error_test() {
+        let overflow = SimulationClock::try_from_metric_timestamp(u64::MAX, 0, 0, 0, 0, 0, 0);
+        assert_eq!(overflow.unwrap_err(), MetricError::Overflow);
+        let clock = SimulationClock::try_from_metric_timestamp(1, 2, 3, 4, 5, 6, 7).unwrap();
+        assert_eq!(clock.current_epoch_seconds(), 123_450_607.0);
+
+        let invalid = SimulationDateTime::from_components(1, 10, 0, 0, 0, 0, 0).validate();
+        assert_eq!(
+            invalid.unwrap_err(),
+            MetricError::OutOfRange {
+                component: "month",
+                value: 10,
+                max: 9
+            }
+        );
+        assert!(SimulationDateTime::from_components(1, 9, 9, 9, 9, 99, 99)
+            .validate()
+            .is_ok());
+
+        let parse = SimulationTimestamp::from_sortable_key("not a key");
+        assert!(matches!(parse, Err(MetricError::Parse(_))));
+
+        let mut clock = SimulationClock::default();
+        for speed in [f64::NAN, f64::INFINITY, -1.0] {
+            assert!(matches!(
+                clock.try_set_clock_speed(speed),
+                Err(MetricError::InvalidSpeed(_))
+            ));
+        }
+        assert_eq!(clock.clock_speed(), 1.0);
+        assert!(clock.try_set_clock_speed(2.5).is_ok());
+        assert_eq!(clock.clock_speed(), 2.5);
+
+        let errors = [
+            MetricError::Overflow,
+            MetricError::OutOfRange {
+                component: "week",
+                value: 12,
+                max: 9,
+            },
+            MetricError::Parse(String::from("x")),
+            MetricError::InvalidSpeed(f64::NAN),
+        ];
+        for error in errors {
+            assert!(!error.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn rollover_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(3, 4, 5, 6, 9, 99, 90);
+        let flags = clock.tick_with_rollovers(Duration::from_secs(5));
+        assert_eq!(flags, RolloverFlags::SECOND);
+
+        let flags = clock.tick_with_rollovers(Duration::from_secs(10));
+        assert!(flags.contains(RolloverFlags::DAY));
+        assert!(!flags.contains(RolloverFlags::WEEK));
+        assert_eq!(clock.current_datetime().day, 7);
+
+        let mut clock = SimulationClock::from_metric_timestamp(3, 9, 9, 9, 9, 99, 99);
+        let flags = clock.tick_with_rollovers(Duration::from_secs(1));
+        let sub_year = RolloverFlags::MONTH
+            | RolloverFlags::WEEK
+            | RolloverFlags::DAY
+            | RolloverFlags::HOUR
+            | RolloverFlags::MINUTE
+            | RolloverFlags::SECOND;
+        assert!(flags.contains(RolloverFlags::YEAR | sub_year));
+        assert!(!flags.contains(RolloverFlags::DECADE));
+    }
+
+    #[test]
+    fn turn_aligned_timestamp_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(0, 0, 0, 0, 0, 1, 0);
+        clock.tick(Duration::from_secs(4));
+        assert_eq!(clock.turn_aligned_timestamp(), clock.current_timestamp());
+
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(
+            clock.turn_aligned_timestamp(),
+            SimulationTimestamp::from_epoch_seconds(104)
+        );
+        clock.tick(Duration::from_secs(4));
+        clock.advance_turn();
+        assert_eq!(clock.turn_number(), 1);
+        clock.tick(Duration::from_secs(3));
+        assert_eq!(clock.current_epoch_seconds(), 113.0);
+        assert_eq!(
+            clock.turn_aligned_timestamp(),
+            SimulationTimestamp::from_epoch_seconds(110)
+        );
+        clock.tick(Duration::from_secs(3));
+        assert_eq!(
+            clock.turn_aligned_timestamp(),
+            SimulationTimestamp::from_epoch_seconds(116)
+        );
+    }
+
+    #[test]
+    fn reset_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed(3.0);
+        clock.set_turn_grace(Duration::from_secs(1));
+        clock.tick(Duration::from_secs(100));
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(2));
+        clock.tick(Duration::from_secs(1));
+        clock.advance_turn();
+        clock.tick(Duration::from_secs(1));
+
+        clock.reset();
+        assert_eq!(clock.current_epoch_seconds(), 0.0);
+        assert_eq!(clock.turn_number(), 0);
+        assert_eq!(clock.stalled_duration(), Duration::ZERO);
+        assert_eq!(clock.clock_speed(), 3.0);
+        assert_eq!(clock.turn_grace(), Duration::from_secs(1));
+
+        clock.tick(Duration::from_secs(10));
+        assert_eq!(clock.current_epoch_seconds(), 30.0);
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.current_epoch_seconds(), 36.0);
+    }
+
+    #[test]
+    fn days_in_month_test() {
+        let days: Vec<SimulationDateTime> = SimulationDateTime::days_in_month(12, 3).collect();
+        assert_eq!(days.len(), 100);
+        assert_eq!(days[0].to_epoch_seconds(), 1_230_000_000);
+        assert_eq!((days[99].week, days[99].day), (9, 9));
+        assert_eq!(days[99].to_epoch_seconds(), 1_239_900_000);
+        assert!(days
+            .windows(2)
+            .all(|pair| pair[1].to_epoch_seconds() - pair[0].to_epoch_seconds() == 100_000));
+        assert!(days.iter().all(|day| day.validate().is_ok()));
+    }
+
+    #[test]
+    fn datetime_ordering_test() {
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |modulus: u64| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 33) % modulus
+        };
+        let mut datetimes: Vec<SimulationDateTime> = (0..256)
+            .map(|_| {
+                SimulationDateTime::from_components(
+                    next(4) as i64,
+                    next(10) as u8,
+                    next(10) as u8,
+                    next(10) as u8,
+                    next(10) as u8,
+                    next(100) as u8,
+                    next(100) as u8,
+                )
+            })
+            .collect();
+        for pair in datetimes.windows(2) {
+            assert_eq!(
+                pair[0].cmp(&pair[1]),
+                pair[0].to_epoch_seconds().cmp(&pair[1].to_epoch_seconds())
+            );
+        }
+        datetimes.sort();
+        assert!(datetimes
+            .windows(2)
+            .all(|pair| pair[0].to_epoch_seconds() <= pair[1].to_epoch_seconds()));
+        assert_eq!(
+            SimulationDateTime::from_components(1, 2, 3, 4, 5, 6, 7),
+            SimulationDateTime::from_epoch_seconds(123_450_607)
+        );
+    }
+
+    #[test]
+    fn atomic_time_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(0, 0, 0, 1, 0, 0, 0);
+        assert_eq!(
+            clock.atomic_time(),
+            SimulationTimestamp::from_epoch_seconds(100_000)
+        );
+
+        let reader = clock.time_reader();
+        let observer = std::thread::spawn(move || {
+            let mut last = reader.now();
+            while last < SimulationTimestamp::from_epoch_seconds(101_000) {
+                let now = reader.now();
+                assert!(now >= last);
+                last = now;
+            }
+            last
+        });
+        for _ in 0..1_000 {
+            clock.tick(Duration::from_secs(1));
+        }
+        assert_eq!(
+            observer.join().unwrap(),
+            SimulationTimestamp::from_epoch_seconds(101_000)
+        );
+        assert_eq!(clock.atomic_time(), clock.current_timestamp());
+
+        let json = serde_json::to_string(&clock).unwrap();
+        let restored: SimulationClock = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.atomic_time(), clock.current_timestamp());
+    }
+
+    #[test]
+    fn datetime_serde_test() {
+        let datetime = SimulationDateTime::from_components(12, 3, 4, 5, 6, 78, 90);
+        let json = serde_json::to_string(&datetime).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":12,"month":3,"week":4,"day":5,"hour":6,"minute":78,"second":90}"#
+        );
+        let restored: SimulationDateTime = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, datetime);
+
+        let invalid = r#"{"year":12,"month":3,"week":4,"day":5,"hour":6,"minute":100,"second":0}"#;
+        let error = serde_json::from_str::<SimulationDateTime>(invalid).unwrap_err();
+        assert!(error.to_string().contains("minute"));
+        let missing = r#"{"year":12,"month":3}"#;
+        assert!(serde_json::from_str::<SimulationDateTime>(missing).is_err());
+    }
+
+    #[test]
+    fn turn_duration_metric_test() {
+        let clock = SimulationClock::default();
+        let turn = clock.turn_duration_metric();
+        assert_eq!(turn.as_duration(), Duration::from_secs(6));
+        assert!((turn.as_unit(MetricScale::Hour) - 0.0006).abs() < 1e-12);
+        assert!((turn.as_unit(MetricScale::Minute) - 0.06).abs() < 1e-12);
+        assert_eq!(turn.as_unit(MetricScale::Second), 6.0);
+    }
+
+    #[test]
+    fn calendar_cycle_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_calendar_cycle(Duration::from_secs(SECONDS_PER_METRIC_YEAR));
+        clock.tick(Duration::from_secs(
+            2 * SECONDS_PER_METRIC_YEAR + 3 * SECONDS_PER_METRIC_DAY,
+        ));
+        assert_eq!(
+            clock.current_datetime(),
+            SimulationDateTime::from_components(0, 0, 0, 3, 0, 0, 0)
+        );
+        assert_eq!(clock.current_epoch_seconds(), 200_300_000.0);
+        assert_eq!(clock.current_timestamp().to_string(), "200300000");
+        assert_eq!(
+            (SimulationTimestamp::from_signed_epoch_seconds(-13) + Duration::from_millis(500))
+                .to_string(),
+            "-12.5"
+        );
+        assert_eq!(
+            SimulationTimestamp::MAX.to_string(),
+            "9223372036854775807.999999999"
+        );
+
+        clock.set_calendar_cycle(Duration::ZERO);
+        assert_eq!(clock.calendar_cycle(), None);
+        assert_eq!(clock.current_datetime().year, 2);
+    }
+
+    #[test]
+    fn same_unit_test() {
+        let morning = SimulationTimestamp::from_components(1, 2, 3, 4, 1, 50, 0);
+        let evening = SimulationTimestamp::from_components(1, 2, 3, 4, 8, 0, 0);
+        let tomorrow = SimulationTimestamp::from_components(1, 2, 3, 5, 1, 50, 0);
+        assert!(morning.same_unit(&evening, MetricScale::Day));
+        assert!(!morning.same_unit(&evening, MetricScale::Hour));
+        assert!(!morning.same_unit(&tomorrow, MetricScale::Day));
+        assert!(morning.same_unit(&tomorrow, MetricScale::Week));
+        assert!(morning.same_unit(&morning, MetricScale::Second));
+    }
+
+    #[test]
+    fn turn_subdivisions_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_turn_subdivisions(4);
+        clock.enable_turn_mode();
+        for _ in 0..2 {
+            assert_eq!(clock.current_phase(), 0);
+            let mut crossings = 0;
+            let mut phases = Vec::new();
+            while !clock.turn_complete() {
+                crossings += clock.tick_phases(Duration::from_millis(500));
+                phases.push(clock.current_phase());
+            }
+            assert_eq!(crossings, 4);
+            assert_eq!(phases.first(), Some(&0));
+            assert_eq!(phases.last(), Some(&3));
+            clock.advance_turn();
+        }
+
+        clock.set_turn_subdivisions(0);
+        assert_eq!(clock.turn_subdivisions(), 1);
+        assert_eq!(clock.tick_phases(Duration::from_secs(6)), 1);
+    }
+
+    #[test]
+    fn saturating_add_duration_test() {
+        let datetime = SimulationDateTime::from_components(7, 9, 9, 9, 9, 99, 99);
+        assert_eq!(
+            datetime.saturating_add_duration(Duration::from_secs(1)),
+            SimulationDateTime::from_components(8, 0, 0, 0, 0, 0, 0)
+        );
+
+        let near_max = SimulationDateTime::from_signed_epoch_seconds(i64::MAX - 9);
+        let max = SimulationDateTime {
+            nanosecond: 999_999_999,
+            ..SimulationDateTime::from_signed_epoch_seconds(i64::MAX)
+        };
+        assert_eq!(
+            near_max.saturating_add_duration(Duration::from_secs(100)),
+            max
+        );
+        assert_eq!(near_max.saturating_add_duration(Duration::MAX), max);
+    }
+
+    #[test]
+    fn session_real_time_test() {
+        let mut clock = SimulationClock::default();
+        clock.tick(Duration::from_millis(16));
+        clock.set_clock_speed(10.0);
+        clock.tick(Duration::from_millis(33));
+        clock.set_clock_speed(0.0);
+        clock.tick(Duration::from_millis(50));
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_millis(1));
+        clock.advance_sim(Duration::from_secs(60));
+        assert_eq!(clock.session_real_time(), Duration::from_millis(100));
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::from_millis(60_346))
+        );
+    }
+
+    #[test]
+    fn turn_progress_bar_test() {
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        assert_eq!(clock.turn_progress_bar(10), "[----------] 0%");
+        assert_eq!(clock.turn_progress_bar(0), "[] 0%");
+        clock.tick(Duration::from_secs(3));
+        assert_eq!(clock.turn_progress(), 0.5);
+        assert_eq!(clock.turn_progress_bar(10), "[#####-----] 50%");
+        assert_eq!(clock.turn_progress_bar(3), "[##-] 50%");
+        assert_eq!(clock.turn_progress_bar(0), "[] 50%");
+        clock.tick(Duration::from_secs(3));
+        assert_eq!(clock.turn_progress_bar(10), "[##########] 100%");
+        assert_eq!(clock.turn_progress_bar(1), "[#] 100%");
+    }
+
+    #[test]
+    fn insert_and_skip_time_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(1, 2, 3, 4, 5, 6, 7);
+        clock.set_clock_speed(2.0);
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(1));
+
+        clock.insert_time(Duration::from_secs(SECONDS_PER_METRIC_HOUR));
+        assert_eq!(
+            clock.current_datetime(),
+            SimulationDateTime::from_components(1, 2, 3, 4, 6, 6, 9)
+        );
+        clock.skip_time(Duration::from_secs(SECONDS_PER_METRIC_MINUTE));
+        assert_eq!(
+            clock.current_datetime(),
+            SimulationDateTime::from_components(1, 2, 3, 4, 6, 5, 9)
+        );
+        assert_eq!(clock.turn_number(), 0);
+        assert_eq!(clock.turn_progress_bar(3), "[#--] 33%");
+
+        clock.skip_time(Duration::MAX);
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from_signed_epoch_seconds(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn next_turn_completion_test() {
+        let mut clock = SimulationClock::from_seconds(500);
+        clock.set_clock_speed(1.0);
+        assert_eq!(clock.next_turn_completion(), None);
+
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_millis(1_250));
+        let expected = SimulationTimestamp::from(
+            Duration::from_secs_f64(clock.current_epoch_seconds()) + clock.turn_time_remaining(),
+        );
+        assert_eq!(clock.next_turn_completion(), Some(expected));
+        assert_eq!(
+            clock.next_turn_completion(),
+            Some(SimulationTimestamp::from_epoch_seconds(503))
+        );
+    }
+
+    #[test]
+    fn from_duration_rounded_test() {
+        let duration = Duration::from_secs_f64(SECONDS_PER_METRIC_HOUR as f64 + 99.6);
+        assert_eq!(
+            SimulationDateTime::from_duration_rounded(duration, RoundingMode::Truncate),
+            SimulationDateTime::from_components(0, 0, 0, 0, 1, 0, 99)
+        );
+        assert_eq!(
+            SimulationDateTime::from_duration_rounded(duration, RoundingMode::Nearest),
+            SimulationDateTime::from_components(0, 0, 0, 0, 1, 1, 0)
+        );
+        let below_half = Duration::from_millis(99_499);
+        assert_eq!(
+            SimulationDateTime::from_duration_rounded(below_half, RoundingMode::Nearest),
+            SimulationDateTime::from_components(0, 0, 0, 0, 0, 0, 99)
+        );
+        let day_end = Duration::from_millis(SECONDS_PER_METRIC_DAY * 1_000 - 500);
+        assert_eq!(
+            SimulationDateTime::from_duration_rounded(day_end, RoundingMode::Nearest),
+            SimulationDateTime::from_components(0, 0, 0, 1, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn turn_completes_within_test() {
+        let mut clock = SimulationClock::default();
+        assert!(!clock.turn_completes_within(Duration::MAX));
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.turn_time_remaining(), Duration::from_secs(4));
+        assert!(!clock.turn_completes_within(Duration::from_millis(3_999)));
+        assert!(clock.turn_completes_within(Duration::from_secs(4)));
+        assert!(clock.turn_completes_within(Duration::from_millis(4_001)));
+    }
+
+    #[test]
+    #[cfg(feature = "transient-turn-state")]
+    fn transient_turn_state_test() {
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(4));
+        let json = serde_json::to_string(&clock).unwrap();
+        assert!(!json.contains("turn_time_remaining"));
+
+        let restored: SimulationClock = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.turn_time_remaining(), Duration::from_secs(6));
+        assert_eq!(restored.current_epoch_seconds(), 4.0);
+
+        clock.disable_turn_mode();
+        let json = serde_json::to_string(&clock).unwrap();
+        let restored: SimulationClock = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.turn_time_remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn clock_from_timestamp_test() {
+        let saved = SimulationTimestamp::from_components(3, 0, 0, 0, 0, 0, 0);
+        let mut clock = SimulationClock::from(saved);
+        assert_eq!(clock.current_timestamp(), saved);
+        assert_eq!(clock.clock_speed(), 1.0);
+        clock.tick(Duration::from_secs(10));
+        assert_eq!(clock.current_epoch_seconds(), 300_000_010.0);
+
+        let mut clock = SimulationClock::at(saved);
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(10));
+        assert!(clock.turn_complete());
+    }
+
+    #[test]
+    fn metric_time_per_turn_test() {
+        let clock = SimulationClock::default();
+        assert_eq!(
+            clock.metric_time_per_turn(),
+            SimulationDateTime::from_components(0, 0, 0, 0, 0, 0, 6)
+        );
+        assert!((clock.turns_per_metric_unit(MetricScale::Hour) - 10_000.0 / 6.0).abs() < 1e-9);
+
+        let clock = SimulationClock {
+            turn_duration: Duration::from_secs(SECONDS_PER_METRIC_HOUR),
+            ..Default::default()
+        };
+        assert_eq!(
+            clock.metric_time_per_turn(),
+            SimulationDateTime::from_components(0, 0, 0, 0, 1, 0, 0)
+        );
+        assert_eq!(clock.turns_per_metric_unit(MetricScale::Hour), 1.0);
+        assert_eq!(clock.turns_per_metric_unit(MetricScale::Day), 10.0);
+    }
+
+    #[test]
+    fn speed_limits_test() {
+        let mut clock = SimulationClock::default();
+        assert_eq!(clock.speed_limits(), (0.0, f64::MAX));
+        clock.set_clock_speed(f64::NAN);
+        assert_eq!(clock.clock_speed(), 1.0);
+        clock.set_clock_speed(-3.0);
+        assert_eq!(clock.clock_speed(), 0.0);
+
+        clock.set_clock_speed(8.0);
+        clock.set_speed_limits(0.0, 4.0);
+        assert_eq!(clock.clock_speed(), 4.0);
+        clock.set_clock_speed(10.0);
+        assert_eq!(clock.clock_speed(), 4.0);
+        assert!(clock.try_set_clock_speed(10.0).is_ok());
+        assert_eq!(clock.clock_speed(), 4.0);
+        clock.set_clock_speed(2.5);
+        assert_eq!(clock.clock_speed(), 2.5);
+
+        clock.set_speed_limits(5.0, 1.0);
+        clock.set_speed_limits(f64::NAN, 1.0);
+        assert_eq!(clock.speed_limits(), (0.0, 4.0));
+    }
+
+    #[test]
+    fn turn_drift_test() {
+        let mut leader = SimulationClock::default();
+        let mut follower = SimulationClock::default();
+        leader.enable_turn_mode();
+        follower.enable_turn_mode();
+        for _ in 0..5 {
+            leader.tick(Duration::from_secs(6));
+            leader.advance_turn();
+        }
+        for _ in 0..2 {
+            follower.tick(Duration::from_secs(6));
+            follower.advance_turn();
+        }
+        assert_eq!(leader.turn_drift(&follower), 3);
+        assert_eq!(follower.turn_drift(&leader), -3);
+        assert_eq!(leader.turn_drift(&leader.clone()), 0);
+    }
+
+    #[test]
+    fn turn_time_remaining_metric_test() {
+        let mut clock = SimulationClock {
+            turn_duration: Duration::from_secs(SECONDS_PER_METRIC_HOUR),
+            ..Default::default()
+        };
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(5_000));
+        let remaining = clock.turn_time_remaining_metric();
+        assert_eq!(remaining.as_unit(MetricScale::Hour), 0.5);
+        assert_eq!(remaining.as_unit(MetricScale::Minute), 50.0);
+    }
+
+    #[test]
+    fn apply_offline_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed(2.0);
+        let applied = clock.apply_offline(Duration::from_secs(3_600), None);
+        assert_eq!(applied, Duration::from_secs(7_200));
+        assert_eq!(clock.current_epoch_seconds(), 7_200.0);
+
+        let applied = clock.apply_offline(
+            Duration::from_secs(3_600),
+            Some(Duration::from_secs(SECONDS_PER_METRIC_HOUR / 2)),
+        );
+        assert_eq!(applied, Duration::from_secs(5_000));
+        assert_eq!(clock.current_epoch_seconds(), 12_200.0);
+        assert_eq!(clock.session_real_time(), Duration::ZERO);
+    }
+
+    #[test]
+    fn symbolic_string_test() {
+        let full = SimulationDateTime::from_components(2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(full.to_symbolic_string(), "2Y 3Mo 4W 5D 06:07:08");
+        let no_week = SimulationDateTime::from_components(2, 3, 0, 5, 6, 7, 8);
+        assert_eq!(no_week.to_symbolic_string(), "2Y 3Mo 5D 06:07:08");
+        let no_year = SimulationDateTime::from_components(0, 0, 1, 0, 9, 99, 0);
+        assert_eq!(no_year.to_symbolic_string(), "1W 09:99:00");
+        let epoch = SimulationDateTime::from_components(0, 0, 0, 0, 0, 0, 0);
+        assert_eq!(epoch.to_symbolic_string(), "00:00:00");
+    }
+
+    #[test]
+    fn monotonic_guard_silent_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_monotonic(true);
+        for _ in 0..100 {
+            clock.tick(Duration::from_millis(16));
+        }
+        clock.set_time(SimulationTimestamp::from_epoch_seconds(0));
+        clock.insert_time(Duration::from_secs(10));
+        clock.skip_time(Duration::from_secs(5));
+        assert_eq!(clock.current_epoch_seconds(), 5.0);
+
+        clock.set_monotonic(false);
+        clock.set_speed_limits(-1.0, 1.0);
+        clock.set_clock_speed(-1.0);
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.current_epoch_seconds(), 3.0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "would rewind")]
+    fn monotonic_guard_triggers_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(0, 0, 0, 0, 0, 1, 0);
+        clock.set_monotonic(true);
+        clock.set_speed_limits(-1.0, 1.0);
+        clock.set_clock_speed(-0.5);
+        clock.tick(Duration::from_secs(2));
+    }
+
+    #[test]
+    fn clock_equality_test() {
+        let mut a = SimulationClock::default();
+        a.tick(Duration::from_secs(10));
+        let mut b = a.clone();
+        assert_eq!(a, b);
+        b.tick(Duration::ZERO);
+        assert_eq!(a, b);
+
+        b.set_clock_speed(1.0 + 1e-9);
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, Duration::ZERO, 1e-6));
+        assert!(!a.approx_eq(&b, Duration::ZERO, 1e-12));
+
+        let mut c = SimulationClock::default();
+        for _ in 0..600 {
+            c.tick(Duration::from_secs_f64(1.0 / 60.0));
+        }
+        assert!(a.approx_eq(&c, Duration::from_micros(1), 0.0));
+        c.enable_turn_mode();
+        assert!(!a.approx_eq(&c, Duration::from_secs(1), 0.0));
+    }
+
+    #[test]
+    fn tick_between_test() {
+        let mut clock = SimulationClock::default();
+        let last = Instant::now();
+        let now = last + Duration::from_millis(250);
+        clock.tick_between(last, now);
+        assert_eq!(clock.current_epoch_seconds(), 0.25);
+        clock.tick_between(now, last);
+        assert_eq!(clock.current_epoch_seconds(), 0.25);
+        assert_eq!(clock.session_real_time(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn timeline_test() {
+        let mut timeline = Timeline::new();
+        timeline.add_speed_change(SimulationTimestamp::from_epoch_seconds(20), 1.0);
+        timeline.add_speed_change(SimulationTimestamp::from_epoch_seconds(10), 0.25);
+        assert_eq!(timeline.len(), 2);
+
+        let mut clock = SimulationClock::default();
+        clock.attach_timeline(timeline);
+        clock.tick(Duration::from_secs(8));
+        assert_eq!(clock.clock_speed(), 1.0);
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.clock_speed(), 0.25);
+        clock.tick(Duration::from_secs(8));
+        assert_eq!(clock.current_epoch_seconds(), 12.0);
+        assert_eq!(clock.clock_speed(), 0.25);
+        clock.tick(Duration::from_secs(32));
+        assert_eq!(clock.current_epoch_seconds(), 20.0);
+        assert_eq!(clock.clock_speed(), 1.0);
+        assert!(clock.timeline().unwrap().is_empty());
+
+        clock.set_clock_speed(3.0);
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(clock.clock_speed(), 3.0);
+        assert!(clock.detach_timeline().is_some());
+    }
+
+    #[test]
+    fn try_from_components_test() {
+        let exact =
+            SimulationTimestamp::try_from_components(4_000_000_000, 0, 0, 0, 0, 0, 1).unwrap();
+        assert_eq!(exact.to_sortable_key(), "00400000000000000001000000000");
+        let saturating = SimulationTimestamp::from_components(4_000_000_000, 0, 0, 0, 0, 0, 1);
+        assert_eq!(saturating, exact);
+        assert_eq!(
+            SimulationTimestamp::from_components(i64::MAX / 100_000_000, 9, 9, 9, 9, 99, 99),
+            SimulationTimestamp::MAX
+        );
+
+        let max =
+            SimulationTimestamp::try_from_components(u32::MAX as i64, 255, 255, 255, 255, 255, 255);
+        assert!(max.is_ok());
+        assert_eq!(
+            SimulationTimestamp::try_from_components(i64::MAX / 100_000_000, 9, 9, 9, 9, 99, 99),
+            Err(MetricError::Overflow)
+        );
+        assert_eq!(
+            SimulationClock::try_from_metric_timestamp(0, 0, 0, 0, 0, u64::MAX, 0).unwrap_err(),
+            MetricError::Overflow
+        );
+    }
+
+    #[test]
+    fn uptime_string_test() {
+        let clock = SimulationClock::from_metric_timestamp(2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(clock.uptime_string(), "2 metric years, 3 metric months");
+        let clock = SimulationClock::from_metric_timestamp(1, 0, 1, 0, 0, 0, 0);
+        assert_eq!(clock.uptime_string(), "1 metric year, 1 metric week");
+        let clock = SimulationClock::from_metric_timestamp(0, 0, 0, 0, 0, 42, 1);
+        assert_eq!(clock.uptime_string(), "42 metric minutes, 1 metric second");
+        let clock = SimulationClock::from_metric_timestamp(0, 0, 0, 0, 0, 0, 7);
+        assert_eq!(clock.uptime_string(), "7 metric seconds");
+        assert_eq!(
+            SimulationClock::default().uptime_string(),
+            "0 metric seconds"
+        );
+
+        let before_epoch = SimulationTimestamp::from_signed_epoch_seconds(
+            -2 * SECONDS_PER_METRIC_YEAR as i64 - 3 * SECONDS_PER_METRIC_MONTH as i64,
+        );
+        assert_eq!(
+            SimulationClock::at(before_epoch).uptime_string(),
+            "-2 metric years, 3 metric months"
+        );
+        let before_epoch = SimulationTimestamp::from_signed_epoch_seconds(-1);
+        assert_eq!(
+            SimulationClock::at(before_epoch).uptime_string(),
+            "-1 metric second"
+        );
+        let before_epoch = SimulationTimestamp::EPOCH - Duration::from_millis(500);
+        assert_eq!(
+            SimulationClock::at(before_epoch).uptime_string(),
+            "0 metric seconds"
+        );
+    }
+
+    #[test]
+    fn on_rollover_test() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+        let days = Arc::new(AtomicU32::new(0));
+        let years = Arc::new(AtomicU32::new(0));
+        let mut clock = SimulationClock::from_seconds(SECONDS_PER_METRIC_DAY - 1);
+        clock.set_clock_speed(1.0);
+        let counter = days.clone();
+        clock.on_rollover(MetricScale::Day, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let counter = years.clone();
+        clock.on_rollover(MetricScale::Year, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(days.load(Ordering::SeqCst), 1);
+        clock.tick(Duration::from_secs(10));
+        assert_eq!(days.load(Ordering::SeqCst), 1);
+        clock.tick(Duration::from_secs(3 * SECONDS_PER_METRIC_DAY));
+        assert_eq!(days.load(Ordering::SeqCst), 4);
+        assert_eq!(years.load(Ordering::SeqCst), 0);
+        let mut copy = clock.clone();
+        copy.tick(Duration::from_secs(SECONDS_PER_METRIC_DAY));
+        assert_eq!(days.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn clock_speed_ratio_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed_ratio(7, 3);
+        assert_eq!(clock.clock_speed_ratio(), Some((7, 3)));
+        for _ in 0..3 {
+            clock.tick(Duration::from_nanos(1));
+        }
+        // Each nanosecond of Real Time scales to 7 / 3 ns, truncated to 2 ns.
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::from_nanos(6))
+        );
+        clock.tick(Duration::new(3, 3));
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::new(7, 13))
+        );
+        clock.set_clock_speed_ratio(1, 0);
+        assert_eq!(clock.clock_speed_ratio(), Some((7, 3)));
+        clock.set_clock_speed(2.0);
+        assert_eq!(clock.clock_speed_ratio(), None);
+        clock.set_speed_limits(0.0, 4.0);
+        clock.set_clock_speed_ratio(9, 2);
+        assert_eq!(clock.clock_speed_ratio(), None);
+        assert_eq!(clock.clock_speed(), 4.0);
+    }
+
+    #[test]
+    fn scenario_progress_test() {
+        let end = SimulationTimestamp::from_epoch_seconds(SECONDS_PER_METRIC_DAY);
+        let mut clock = SimulationClock::default();
+        assert_eq!(clock.scenario_progress(end), 0.0);
+        clock.set_time(SimulationTimestamp::from_epoch_seconds(
+            SECONDS_PER_METRIC_DAY / 2,
+        ));
+        assert_eq!(clock.scenario_progress(end), 0.5);
+        clock.set_time(SimulationTimestamp::from_epoch_seconds(
+            3 * SECONDS_PER_METRIC_DAY,
+        ));
+        assert_eq!(clock.scenario_progress(end), 1.0);
+        let start = SimulationTimestamp::from_epoch_seconds(2 * SECONDS_PER_METRIC_DAY);
+        let end = SimulationTimestamp::from_epoch_seconds(4 * SECONDS_PER_METRIC_DAY);
+        assert_eq!(clock.scenario_progress_between(start, end), 0.5);
+        clock.set_time(SimulationTimestamp::from_epoch_seconds(0));
+        assert_eq!(clock.scenario_progress_between(start, end), 0.0);
+    }
+
+    #[test]
+    fn datetime_from_str_test() {
+        let expected = SimulationDateTime::from_components(12, 3, 4, 5, 6, 7, 8);
+        let legacy: SimulationDateTime = "12-03-04-05@06:07:08.0000".parse().unwrap();
+        let clean: SimulationDateTime = "12-03-04-05@06:07:08".parse().unwrap();
+        assert_eq!(legacy, expected);
+        assert_eq!(clean, expected);
+        let round_trip: SimulationDateTime = expected.to_string().parse().unwrap();
+        assert_eq!(round_trip, expected);
+        for input in [
+            "12-03-04-05@06:07:08.5000000001",
+            "12-03-04-05@06:07:08.",
+            "12-03-04@06:07:08",
+            "12-03-04-05 06:07:08",
+            "12-03-04-05@06:07:+8",
+        ] {
+            assert_eq!(
+                input.parse::<SimulationDateTime>(),
+                Err(MetricError::Parse(input.to_string()))
+            );
+        }
+        assert!(matches!(
+            "12-10-04-05@06:07:08".parse::<SimulationDateTime>(),
+            Err(MetricError::OutOfRange {
+                component: "month",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn timestamp_grid_test() {
+        let grid = Duration::from_secs(100);
+        let on_grid = SimulationTimestamp::from_epoch_seconds(300);
+        assert_eq!(on_grid.floor_to(grid), on_grid);
+        assert_eq!(on_grid.ceil_to(grid), on_grid);
+        let off_grid = SimulationTimestamp::from_epoch_seconds(342);
+        assert_eq!(off_grid.floor_to(grid), on_grid);
+        assert_eq!(
+            off_grid.ceil_to(grid),
+            SimulationTimestamp::from_epoch_seconds(400)
+        );
+        let just_after = SimulationTimestamp::from(Duration::new(300, 1));
+        assert_eq!(just_after.floor_to(grid), on_grid);
+        assert_eq!(
+            just_after.ceil_to(grid),
+            SimulationTimestamp::from_epoch_seconds(400)
+        );
+        assert_eq!(off_grid.floor_to(Duration::ZERO), off_grid);
+        assert_eq!(off_grid.ceil_to(Duration::ZERO), off_grid);
+        assert_eq!(off_grid.round_to(grid), on_grid);
+        assert_eq!(
+            SimulationTimestamp::from_epoch_seconds(350).round_to(grid),
+            SimulationTimestamp::from_epoch_seconds(400)
+        );
+        assert_eq!(off_grid.round_to(Duration::ZERO), off_grid);
+
+        let noon = SimulationTimestamp::from_components(3, 2, 1, 4, 5, 6, 7);
+        assert_eq!(
+            noon.floor_to(MetricUnit::Day),
+            SimulationTimestamp::from_components(3, 2, 1, 4, 0, 0, 0)
+        );
+        assert_eq!(
+            noon.ceil_to(MetricUnit::Day),
+            SimulationTimestamp::from_components(3, 2, 1, 5, 0, 0, 0)
+        );
+        assert_eq!(
+            noon.round_to(MetricUnit::Day),
+            SimulationTimestamp::from_components(3, 2, 1, 5, 0, 0, 0)
+        );
+        assert_eq!(
+            noon.round_to(MetricUnit::Month),
+            SimulationTimestamp::from_components(3, 2, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            noon.floor_to(MetricUnit::Millennium),
+            SimulationTimestamp::from_epoch_seconds(0)
+        );
+        let before_epoch = SimulationTimestamp::from_signed_epoch_seconds(-30_000);
+        assert_eq!(
+            before_epoch.floor_to(MetricUnit::Day),
+            SimulationTimestamp::from_signed_epoch_seconds(-100_000)
+        );
+        assert_eq!(
+            before_epoch.round_to(MetricUnit::Day),
+            SimulationTimestamp::from_epoch_seconds(0)
+        );
+
+        let earliest = SimulationTimestamp::MIN + Duration::from_secs(1);
+        assert_eq!(earliest.floor_to(MetricUnit::Day), SimulationTimestamp::MIN);
+        assert_eq!(
+            SimulationTimestamp::MAX.ceil_to(MetricUnit::Day),
+            SimulationTimestamp::MAX
+        );
+    }
+
+    #[test]
+    fn datetime_tuple_test() {
+        let datetime = SimulationDateTime::from_components(12, 3, 4, 5, 6, 7, 8);
+        assert_eq!(datetime.as_tuple(), (12, 3, 4, 5, 6, 7, 8));
+        assert_eq!(
+            SimulationDateTime::from_tuple(datetime.as_tuple()),
+            datetime
+        );
+        match datetime.as_tuple() {
+            (_, _, _, 5, hour, ..) => assert_eq!(hour, 6),
+            _ => panic!("day should be 5"),
+        }
+    }
+
+    #[test]
+    fn tick_catch_up_test() {
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        assert_eq!(clock.tick_catch_up(Duration::from_secs(33)), 5);
+        assert_eq!(clock.current_epoch_seconds(), 33.0);
+        assert_eq!(clock.turn_number(), 5);
+        assert_eq!(clock.turn_time_remaining(), Duration::from_secs(3));
+        assert_eq!(clock.tick_catch_up(Duration::from_secs(3)), 1);
+        assert!(clock.turn_complete());
+        assert_eq!(clock.tick_catch_up(Duration::from_secs(30)), 0);
+        assert_eq!(clock.current_epoch_seconds(), 36.0);
+        let mut real_time = SimulationClock::default();
+        assert_eq!(real_time.tick_catch_up(Duration::from_secs(33)), 0);
+        assert_eq!(real_time.current_epoch_seconds(), 33.0);
+    }
+
+    #[test]
+    fn strict_turn_mode_test() {
+        let mut clock = SimulationClock::default();
+        clock.tick(Duration::from_secs(4));
+        clock.enable_strict_turn_mode();
+        clock.tick(Duration::from_secs(100));
+        clock.advance_sim(Duration::from_secs(100));
+        assert_eq!(clock.current_epoch_seconds(), 4.0);
+        clock.advance_turn();
+        assert_eq!(clock.current_epoch_seconds(), 10.0);
+        clock.advance_turn();
+        assert_eq!(clock.current_epoch_seconds(), 16.0);
+        assert_eq!(clock.turn_number(), 2);
+        assert_eq!(
+            clock.next_turn_completion(),
+            Some(SimulationTimestamp::from_epoch_seconds(22))
+        );
+        clock.disable_turn_mode();
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(clock.current_epoch_seconds(), 17.0);
+    }
+
+    #[test]
+    fn local_offset_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(3, 0, 0, 5, 0, 0, 0);
+        clock.set_local_offset(SignedDuration::positive(Duration::from_secs(
+            2 * SECONDS_PER_METRIC_HOUR,
+        )));
+        assert_eq!(
+            clock.current_datetime(),
+            SimulationDateTime::from_components(3, 0, 0, 5, 2, 0, 0)
+        );
+        assert_eq!(
+            clock.utc_datetime(),
+            SimulationDateTime::from_components(3, 0, 0, 5, 0, 0, 0)
+        );
+        clock.set_local_offset(SignedDuration::negative(Duration::from_secs(
+            SECONDS_PER_METRIC_DAY,
+        )));
+        assert_eq!(
+            clock.current_datetime(),
+            SimulationDateTime::from_components(3, 0, 0, 4, 0, 0, 0)
+        );
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from_components(3, 0, 0, 5, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn mode_predicates_test() {
+        let mut clock = SimulationClock::default();
+        assert_eq!(clock.mode(), ClockMode::RealTime);
+        assert!(clock.is_real_time());
+        assert!(!clock.is_turn_based());
+        clock.enable_turn_mode();
+        assert_eq!(clock.mode(), ClockMode::TurnBased);
+        assert!(!clock.is_real_time());
+        assert!(clock.is_turn_based());
+        clock.enable_strict_turn_mode();
+        assert_eq!(clock.mode(), ClockMode::StrictTurn);
+        assert!(clock.is_turn_based());
+        clock.disable_turn_mode();
+        assert!(clock.is_real_time());
+    }
+
+    #[test]
+    fn advance_units_test() {
+        let mut clock = SimulationClock::default();
+        assert_eq!(clock.advance_days(3), Ok(0));
+        assert_eq!(clock.current_epoch_seconds(), 300_000.0);
+        assert_eq!(clock.advance_years(u64::MAX), Err(MetricError::Overflow));
+        assert_eq!(clock.current_epoch_seconds(), 300_000.0);
+
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        assert_eq!(clock.advance_hours(1), Ok(1_666));
+        assert_eq!(clock.current_epoch_seconds(), 10_000.0);
+        assert_eq!(clock.turn_time_remaining(), Duration::from_secs(2));
+
+        let mut clock = SimulationClock::default();
+        clock.enable_strict_turn_mode();
+        assert_eq!(clock.advance_hours(1), Ok(1_666));
+        assert_eq!(clock.current_epoch_seconds(), 9_996.0);
+        assert_eq!(clock.turn_number(), 1_666);
+    }
+
+    #[test]
+    fn just_completed_turn_test() {
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(4));
+        assert!(!clock.just_completed_turn());
+        clock.tick(Duration::from_secs(4));
+        assert!(clock.just_completed_turn());
+        assert!(clock.turn_complete());
+        clock.tick(Duration::from_secs(4));
+        assert!(!clock.just_completed_turn());
+        assert!(clock.turn_complete());
+        clock.advance_turn();
+        clock.tick(Duration::from_secs(6));
+        assert!(clock.just_completed_turn());
+        clock.advance_turn();
+        assert!(!clock.just_completed_turn());
+    }
+
+    #[test]
+    fn tick_from_parent_test() {
+        let mut parent = SimulationClock::default();
+        let mut child = SimulationClock::default();
+        child.set_clock_speed(50.0);
+        let before = parent.current_timestamp();
+        parent.set_clock_speed(2.0);
+        parent.tick(Duration::from_secs(5));
+        let parent_delta = parent
+            .current_timestamp()
+            .duration_since(&before)
+            .as_duration();
+        child.tick_from_parent(parent_delta, 10.0);
+        assert_eq!(child.current_epoch_seconds(), 100.0);
+        child.tick_from_parent(parent_delta, 0.5);
+        assert_eq!(child.current_epoch_seconds(), 105.0);
+        child.tick_from_parent(parent_delta, f64::NAN);
+        child.tick_from_parent(parent_delta, -1.0);
+        assert_eq!(child.current_epoch_seconds(), 105.0);
+    }
+
+    #[test]
+    fn tick_speed_saturates_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed(1e18);
+        clock.tick(Duration::from_secs(SECONDS_PER_METRIC_DAY));
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::MAX)
+        );
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn diff_clocks_test() {
+        let mut a = SimulationClock::default();
+        let mut b = SimulationClock::default();
+        assert_eq!(diff_clocks(&a, &b), "");
+        a.tick(Duration::from_secs(3));
+        b.set_clock_speed(2.0);
+        b.tick(Duration::from_secs(1));
+        let diff = diff_clocks(&a, &b);
+        assert_eq!(diff.lines().count(), 2);
+        assert!(diff.contains("time: "));
+        assert!(diff.contains("clock_speed: 1.0 | 2.0"));
+        assert!(!diff.contains("mode"));
+
+        let mut a = SimulationClock::default();
+        let mut b = SimulationClock::default();
+        b.set_turn_epsilon(Duration::from_millis(1));
+        a.tick(Duration::from_secs(1));
+        b.tick(Duration::from_secs(1));
+        a.enable_turn_mode();
+        b.tick(Duration::from_secs(1));
+        b.enable_turn_mode();
+        a.tick(Duration::from_secs(1));
+        let diff = diff_clocks(&a, &b);
+        assert_eq!(diff.lines().count(), 3);
+        assert!(diff.contains("turn_start: "));
+        assert!(diff.contains("turn_epsilon: 1µs | 1ms"));
+        assert!(diff.contains("turn_time_remaining: 5s | 6s"));
+    }
+
+    #[test]
+    fn partial_deserialize_test() {
+        let minimal: SimulationClock = serde_json::from_str(r#"{"epoch_seconds": 500}"#).unwrap();
+        assert_eq!(
+            minimal,
+            SimulationClock::at(SimulationTimestamp::from_epoch_seconds(500))
+        );
+        assert_eq!(minimal.clock_speed(), 1.0);
+        assert!(minimal.is_real_time());
+        assert_eq!(
+            minimal.turn_duration_metric().as_duration(),
+            Duration::from_secs(6)
+        );
+        assert_eq!(
+            minimal.atomic_time(),
+            SimulationTimestamp::from_epoch_seconds(500)
+        );
+        let empty: SimulationClock = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, SimulationClock::default());
+
+        let mut full = SimulationClock::from_metric_timestamp(1, 2, 3, 4, 5, 6, 7);
+        full.set_clock_speed(3.0);
+        full.enable_turn_mode();
+        let restored: SimulationClock =
+            serde_json::from_str(&serde_json::to_string(&full).unwrap()).unwrap();
+        assert_eq!(restored, full);
+    }
+
+    #[test]
+    fn tick_scaled_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed(2.0);
+        clock.tick_scaled(Duration::from_secs(10), 0.5);
+        assert_eq!(clock.current_epoch_seconds(), 5.0);
+        assert_eq!(clock.clock_speed(), 2.0);
+        clock.tick(Duration::from_secs(10));
+        assert_eq!(clock.current_epoch_seconds(), 25.0);
+        clock.tick_scaled(Duration::from_secs(10), f64::NAN);
+        assert_eq!(clock.current_epoch_seconds(), 45.0);
+        clock.set_speed_limits(0.0, 3.0);
+        clock.tick_scaled(Duration::from_secs(10), 100.0);
+        assert_eq!(clock.current_epoch_seconds(), 75.0);
+        assert_eq!(clock.clock_speed(), 2.0);
+    }
+
+    #[test]
+    fn datetime_after_test() {
+        let clock = SimulationClock::from_metric_timestamp(4, 3, 2, 1, 5, 6, 7);
+        assert_eq!(
+            clock.datetime_after(Duration::from_secs(10_000)),
+            SimulationDateTime::from_components(4, 3, 2, 1, 6, 6, 7)
+        );
+        assert_eq!(
+            clock.datetime_after(Duration::ZERO),
+            clock.current_datetime()
+        );
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from_components(4, 3, 2, 1, 5, 6, 7)
+        );
+        assert_eq!(
+            clock.datetime_after(Duration::MAX),
+            SimulationDateTime {
+                nanosecond: 999_999_999,
+                ..SimulationDateTime::from_signed_epoch_seconds(i64::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn datetime_display_alternate_test() {
+        let datetime = SimulationDateTime::from_components(12, 3, 4, 5, 6, 7, 8);
+        assert_eq!(format!("{}", datetime), "12-03-04-05@06:07:08");
+        assert_eq!(
+            format!("{:#}", datetime),
+            "year: 12\nmonth: 3\nweek: 4\nday: 5\nhour: 6\nminute: 7\nsecond: 8"
+        );
+        assert_eq!(
+            format!("{:?}", datetime),
+            "SimulationDateTime { year: 12, month: 3, week: 4, day: 5, hour: 6, minute: 7, second: 8, nanosecond: 0 }"
+        );
+    }
+
+    #[cfg(feature = "rng")]
+    #[test]
+    fn from_rng_test() {
+        struct XorShift(u64);
+        impl rand_core::RngCore for XorShift {
+            fn next_u32(&mut self) -> u32 {
+                (self.next_u64() >> 32) as u32
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dest)
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..1_000 {
+            let mut clock = SimulationClock::from_rng(&mut rng);
+            assert!(clock.clock_speed() > 0.0 && clock.clock_speed() <= 1000.0);
+            let before = clock.current_timestamp();
+            clock.tick(Duration::from_secs(SECONDS_PER_METRIC_DAY));
+            assert!(clock.current_timestamp() >= before);
+        }
+    }
+
+    #[test]
+    fn average_speed_test() {
+        let mut clock = SimulationClock::default();
+        clock.set_clock_speed(3.0);
+        assert_eq!(clock.average_speed(), 3.0);
+        clock.set_speed_sample_window(3);
+        clock.set_clock_speed(1.0);
+        clock.tick(Duration::from_secs(1));
+        clock.set_clock_speed(2.0);
+        clock.tick(Duration::from_secs(1));
+        clock.set_clock_speed(6.0);
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.average_speed(), 15.0 / 4.0);
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(clock.average_speed(), 20.0 / 4.0);
+        clock.set_speed_sample_window(1);
+        assert_eq!(clock.average_speed(), 6.0);
+    }
+
+    #[test]
+    fn current_pair_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(7, 1, 2, 3, 4, 5, 6);
+        clock.set_clock_speed(1.5);
+        clock.tick(Duration::from_millis(2_500));
+        let (timestamp, datetime) = clock.current();
+        assert_eq!(timestamp, clock.current_timestamp());
+        assert_eq!(datetime, clock.current_datetime());
+        clock.set_local_offset(SignedDuration::positive(Duration::from_secs(
+            SECONDS_PER_METRIC_DAY,
+        )));
+        assert_eq!(clock.current().1, clock.current_datetime());
+    }
+
+    #[test]
+    fn turn_epsilon_test() {
+        let mut clock = SimulationClock::default();
+        assert_eq!(clock.turn_epsilon(), Duration::from_micros(1));
+        clock.enable_turn_mode();
+        let frame = Duration::from_secs_f64(1.0 / 60.0);
+        for turn in 1..=3 {
+            let mut frames = 0;
+            while !clock.turn_complete() {
+                clock.tick(frame);
+                frames += 1;
+                assert!(frames <= 361, "turn {} never completed", turn);
+            }
+            clock.advance_turn();
+            assert_eq!(clock.turn_number(), turn);
+        }
+        clock.set_turn_epsilon(Duration::from_millis(10));
+        clock.tick(Duration::from_millis(5_995));
+        assert!(clock.turn_complete());
+        assert_eq!(clock.turn_aligned_timestamp(), clock.current_timestamp());
+
+        clock.advance_turn();
+        clock.set_turn_epsilon(Duration::from_micros(1));
+        clock.tick(Duration::from_millis(5_995));
+        assert!(!clock.turn_complete());
+        let turn_end = clock.current_timestamp() + Duration::from_millis(5);
+        clock.set_turn_epsilon(Duration::from_millis(10));
+        assert!(clock.turn_complete());
+        assert_eq!(clock.current_timestamp(), turn_end);
+        clock.advance_turn();
+        assert_eq!(clock.turn_number(), 5);
+    }
+
+    #[test]
+    fn year_phase_test() {
+        assert_eq!(SimulationClock::default().year_phase(), 0.0);
+        let clock = SimulationClock::from_metric_timestamp(3, 0, 0, 0, 0, 0, 0);
+        assert_eq!(clock.year_phase(), 0.0);
+        let clock = SimulationClock::from_metric_timestamp(3, 5, 0, 0, 0, 0, 0);
+        assert_eq!(clock.year_phase(), 0.5);
+        let clock = SimulationClock::from_metric_timestamp(3, 9, 9, 9, 9, 99, 99);
+        assert!(clock.year_phase() < 1.0);
+    }
+
+    #[test]
+    fn snap_to_turn_start_test() {
+        let mut clock = SimulationClock::from_seconds(100);
+        clock.set_clock_speed(1.0);
+        clock.snap_to_turn_start();
+        assert_eq!(clock.current_epoch_seconds(), 100.0);
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.current_epoch_seconds(), 102.0);
+        clock.snap_to_turn_start();
+        assert_eq!(clock.current_epoch_seconds(), 100.0);
+        assert_eq!(clock.turn_time_remaining(), Duration::from_secs(3));
+        assert_eq!(
+            clock.atomic_time(),
+            SimulationTimestamp::from_epoch_seconds(100)
+        );
+
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        clock.tick(Duration::from_secs(2));
+        clock.skip_time(Duration::from_secs(2));
+        clock.snap_to_turn_start();
+        assert_eq!(clock.current_epoch_seconds(), -2.0);
+        assert_eq!(clock.turn_time_remaining(), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn try_from_datetime_test() {
+        let latest = SimulationDateTime::from_components(u32::MAX as i64, 9, 9, 9, 9, 99, 99);
+        assert_eq!(
+            SimulationTimestamp::try_from_datetime(&latest),
+            Ok(SimulationTimestamp::from(latest))
+        );
+        let too_late = SimulationDateTime::from_components(i64::MAX, 0, 0, 0, 0, 0, 0);
+        assert_eq!(
+            SimulationTimestamp::try_from_datetime(&too_late),
+            Err(MetricError::Overflow)
+        );
+        let invalid = SimulationDateTime::from_components(1, 12, 0, 0, 0, 0, 0);
+        assert_eq!(
+            SimulationTimestamp::try_from_datetime(&invalid),
+            Err(MetricError::OutOfRange {
+                component: "month",
+                value: 12,
+                max: 9
+            })
+        );
+    }
+
+    #[test]
+    fn is_rest_day_test() {
+        let day = |day| SimulationDateTime::from_components(0, 0, 0, day, 0, 0, 0);
+        assert!(!day(7).is_rest_day(8));
+        assert!(day(8).is_rest_day(8));
+        assert!(day(9).is_rest_day(8));
+        assert!((0..10).all(|d| !day(d).is_rest_day(10)));
+        assert!((0..10).all(|d| day(d).is_rest_day(0)));
+    }
+
+    #[test]
+    fn tick_detailed_test() {
+        let mut clock = SimulationClock::from_metric_timestamp(3, 4, 5, 6, 9, 99, 90);
+        clock.enable_turn_mode();
+        let start = clock.current_timestamp();
+        let harvest = clock.schedule_event(start + Duration::from_secs(11));
+        let winter = clock.schedule_event(start + Duration::from_secs(20));
+        let report = clock.tick_detailed(Duration::from_secs(12));
+        assert_eq!(
+            report.sim_delta,
+            SignedDuration::positive(Duration::from_secs(12))
+        );
+        assert_eq!(report.turns_completed, 2);
+        assert!(report.rollovers.contains(RolloverFlags::DAY));
+        assert!(!report.rollovers.contains(RolloverFlags::WEEK));
+        assert_eq!(report.due_events, vec![harvest]);
+        assert_eq!(clock.pending_events(), 1);
+        assert_ne!(harvest, winter);
+
+        let report = clock.tick_detailed(Duration::from_secs(12));
+        assert_eq!(report, TickReport::default());
+    }
+
+    #[test]
+    fn timestamp_arithmetic_test() {
+        let start = SimulationTimestamp::from_components(3, 4, 5, 6, 2, 0, 0);
+        let end = start + MetricDuration::from_metric_hours(3);
+        assert_eq!(
+            end,
+            SimulationTimestamp::from_components(3, 4, 5, 6, 5, 0, 0)
+        );
+        assert_eq!(end - Duration::from_secs(30_000), start);
+
+        let mut moving = start;
+        moving += Duration::from_secs(100);
+        moving += MetricDuration::from_metric_days(1);
+        assert_eq!(
+            moving,
+            SimulationTimestamp::from_components(3, 4, 5, 7, 2, 1, 0)
+        );
+        moving -= MetricDuration::from_metric_days(1);
+        moving -= Duration::from_secs(100);
+        assert_eq!(moving, start);
+
+        let earliest = SimulationTimestamp::from_signed_epoch_seconds(i64::MIN);
+        assert_eq!(earliest - MetricDuration::from_metric_days(1), earliest);
+        let mut latest = SimulationTimestamp::from(Duration::MAX);
+        latest += Duration::from_secs(1);
+        assert_eq!(latest, SimulationTimestamp::from(Duration::MAX));
+    }
+
+    #[test]
+    fn duration_since_test() {
+        let event = SimulationTimestamp::from_epoch_seconds(30_000);
+        let now = SimulationTimestamp::from_epoch_seconds(10_000);
+        assert_eq!(
+            event.duration_since(&now),
+            MetricDuration::from_metric_hours(2)
+        );
+        assert_eq!(now.duration_since(&event), MetricDuration::default());
+        assert_eq!(
+            event.signed_duration_since(&now),
+            SignedDuration::positive(Duration::from_secs(20_000))
+        );
+        assert_eq!(
+            now.signed_duration_since(&event),
+            SignedDuration::negative(Duration::from_secs(20_000))
+        );
+        assert_eq!(now.signed_duration_since(&now), SignedDuration::default());
+    }
+
+    #[test]
+    fn timestamp_keys_test() {
+        use std::collections::{BTreeMap, HashSet};
+        let early = SimulationTimestamp::from_epoch_seconds(10);
+        let late = SimulationTimestamp::from_epoch_seconds(20);
+        let schedule: BTreeMap<_, _> = [(late, "harvest"), (early, "plant")].into();
+        assert_eq!(
+            schedule.values().copied().collect::<Vec<_>>(),
+            ["plant", "harvest"]
+        );
+        let seen: HashSet<_> = [early, late, early].into();
+        assert_eq!(seen.len(), 2);
+        let copied = early;
+        assert_eq!(copied, early);
+    }
+
+    #[test]
+    fn checked_arithmetic_test() {
+        let far = SimulationTimestamp::from_signed_epoch_seconds(i64::MAX - 10);
+        let millennium = MetricDuration::from_metric_millennia(1).as_duration();
+        assert_eq!(far.checked_add(millennium), None);
+        assert_eq!(
+            far.checked_add(Duration::from_secs(10)),
+            Some(SimulationTimestamp::from_signed_epoch_seconds(i64::MAX))
+        );
+        let early = SimulationTimestamp::from_signed_epoch_seconds(i64::MIN + 10);
+        assert_eq!(early.checked_sub(millennium), None);
+        let start = SimulationTimestamp::from_epoch_seconds(500);
+        assert_eq!(
+            start.checked_sub(Duration::from_secs(600)),
+            Some(SimulationTimestamp::from_signed_epoch_seconds(-100))
+        );
+        assert_eq!(
+            start.checked_mul_speed(2.0),
+            Some(SimulationTimestamp::from_epoch_seconds(1_000))
+        );
+        assert_eq!(far.checked_mul_speed(2.0), None);
+        assert_eq!(start.checked_mul_speed(f64::NAN), None);
+        assert_eq!(start.checked_mul_speed(-1.0), None);
+
+        let mut clock = SimulationClock::at(far);
+        assert_eq!(clock.checked_add(millennium), None);
+        assert_eq!(
+            clock.checked_sub(Duration::from_secs(10)),
+            Some(SimulationTimestamp::from_signed_epoch_seconds(
+                i64::MAX - 20
+            ))
+        );
+        clock.set_clock_speed_ratio(3, 2);
+        assert_eq!(
+            clock.checked_mul_speed(Duration::from_secs(10)),
+            Some(Duration::from_secs(15))
+        );
+        clock.set_clock_speed(1e12);
+        assert_eq!(
+            clock.checked_mul_speed(Duration::from_secs(u64::MAX / 2)),
+            None
+        );
+    }
+
+    #[test]
+    fn saturating_arithmetic_test() {
+        let start = SimulationTimestamp::from_epoch_seconds(500);
+        assert_eq!(
+            start.saturating_add(Duration::MAX),
+            SimulationTimestamp::from(Duration::MAX)
+        );
+        assert_eq!(
+            start.saturating_sub(Duration::from_secs(501)),
+            SimulationTimestamp::from_signed_epoch_seconds(-1)
+        );
+        assert_eq!(
+            start.saturating_sub(Duration::MAX),
+            SimulationTimestamp::from_signed_epoch_seconds(i64::MIN)
+        );
+        assert_eq!(
+            start.saturating_sub(Duration::from_secs(100)),
+            SimulationTimestamp::from_epoch_seconds(400)
+        );
+
+        let mut clock = SimulationClock::at(start);
+        clock.set_clock_speed(f64::MAX);
+        clock.tick_saturating(Duration::MAX);
+        assert_eq!(
+            clock.current_timestamp(),
+            SimulationTimestamp::from(Duration::MAX)
+        );
+
+        let mut clock = SimulationClock::default();
+        clock.enable_turn_mode();
+        clock.tick_saturating(Duration::from_secs(6));
+        clock.tick_saturating(Duration::MAX);
+        clock.tick_saturating(Duration::MAX);
+        assert_eq!(clock.stalled_duration(), Duration::MAX);
+    }
+
+    #[test]
+    fn tick_saturating_test() {
+        let mut clock = SimulationClock::at(SimulationTimestamp::MIN + Duration::from_secs(5));
+        clock.set_speed_limits(f64::MIN, f64::MAX);
+        clock.set_clock_speed(f64::MIN);
+        clock.tick_saturating(Duration::MAX);
+        assert_eq!(clock.current_timestamp(), SimulationTimestamp::MIN);
+        clock.set_clock_speed(f64::MAX);
+        clock.tick_saturating(Duration::MAX);
+        clock.tick_saturating(Duration::MAX);
+        assert_eq!(clock.current_timestamp(), SimulationTimestamp::MAX);
+        assert_eq!(clock.session_real_time(), Duration::MAX);
+
+        let mut clock = SimulationClock::from_metric_timestamp(0, 0, 0, 0, 0, 1, 0);
+        clock.set_monotonic(true);
+        clock.set_speed_limits(-1.0, 1.0);
+        clock.set_clock_speed(-0.5);
+        clock.tick_saturating(Duration::from_secs(2));
+        assert_eq!(clock.current_epoch_seconds(), 100.0);
+
+        let mut clock = SimulationClock::default();
+        clock.set_speed_limits(0.0, f64::MAX);
+        clock.set_clock_speed_ratio(u32::MAX, 1);
+        clock.enable_turn_mode();
+        clock.tick_saturating(Duration::MAX);
+        assert!(clock.turn_complete());
+        clock.tick_saturating(Duration::MAX);
+        assert_eq!(clock.stalled_duration(), Duration::MAX);
+    }
+
+    #[test]
+    fn pre_epoch_test() {
+        let founding = SimulationTimestamp::from_signed_epoch_seconds(-1);
+        let datetime = SimulationDateTime::from(founding);
+        assert_eq!(
+            datetime,
+            SimulationDateTime::from_components(-1, 9, 9, 9, 9, 99, 99)
+        );
+        assert_eq!(datetime.to_string(), "-0001-09-09-09@09:99:99");
+        assert_eq!(
+            "-0001-09-09-09@09:99:99"
+                .parse::<SimulationDateTime>()
+                .map(SimulationTimestamp::from),
+            Ok(founding)
+        );
+        assert_eq!(SimulationTimestamp::from(datetime), founding);
+        assert!(founding.is_before_epoch());
+
+        let prologue = SimulationTimestamp::try_from_components(-3, 4, 0, 0, 0, 0, 0).unwrap();
+        assert_eq!(prologue.epoch_seconds(), -260_000_000);
+        let mut clock = SimulationClock::at(prologue);
+        assert_eq!(clock.current_datetime().year, -3);
+        assert_eq!(clock.atomic_time(), prologue);
+        let flags = clock.tick_with_rollovers(Duration::from_secs(260_000_000));
+        assert!(flags.contains(RolloverFlags::MILLENNIUM | RolloverFlags::YEAR));
+        assert_eq!(clock.current_epoch_seconds(), 0.0);
+        let flags = clock.tick_with_rollovers(Duration::from_secs(1));
+        assert_eq!(flags, RolloverFlags::SECOND);
+        clock.set_time(SimulationTimestamp::from_signed_epoch_seconds(-1_500));
+        let json = serde_json::to_string(&clock).unwrap();
+        let restored: SimulationClock = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, clock);
+
+        let keys: Vec<String> = [-1_500, -1, 0, 1_500]
+            .into_iter()
+            .map(|seconds| {
+                SimulationTimestamp::from_signed_epoch_seconds(seconds).to_sortable_key()
+            })
+            .collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            SimulationTimestamp::from_sortable_key(&keys[0]),
+            Ok(SimulationTimestamp::from_signed_epoch_seconds(-1_500))
+        );
+    }
+
+    #[test]
+    fn datetime_try_from_components_test() {
+        assert_eq!(
+            SimulationDateTime::try_from_components(5, 2, 7, 9, 4, 12, 34),
+            Ok(SimulationDateTime::from_components(5, 2, 7, 9, 4, 12, 34))
+        );
+        assert_eq!(
+            SimulationDateTime::try_from_components(5, 27, 99, 0, 0, 0, 0),
+            Err(MetricError::OutOfRange {
+                component: "month",
+                value: 27,
+                max: 9,
+            })
+        );
+        assert_eq!(
+            SimulationDateTime::try_from_components(0, 0, 0, 0, 0, 100, 0),
+            Err(MetricError::OutOfRange {
+                component: "minute",
+                value: 100,
+                max: 99,
+            })
+        );
+
+        assert_eq!(
+            SimulationDateTime::from_components_normalized(5, 27, 99, 0, 0, 0, 150),
+            SimulationDateTime::from_components(8, 6, 9, 0, 0, 1, 50)
+        );
+        assert_eq!(
+            SimulationDateTime::from_components_normalized(-1, 9, 9, 9, 9, 99, 100),
+            SimulationDateTime::from_components(0, 0, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationDateTime::from_components_normalized(i64::MAX, 9, 0, 0, 0, 0, 0),
+            SimulationDateTime::from_signed_epoch_seconds(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn datetime_builder_test() {
+        let (datetime, timestamp) = SimulationDateTime::builder()
+            .year(2)
+            .month(9)
+            .day(12)
+            .hour(7)
+            .minute(150)
+            .second(250)
+            .build();
+        assert_eq!(
+            datetime,
+            SimulationDateTime::from_components(2, 9, 1, 2, 8, 52, 50)
+        );
+        assert_eq!(timestamp, SimulationTimestamp::from(datetime));
+
+        let (datetime, _) = SimulationDateTime::builder().year(-1).week(10).build();
+        assert_eq!(
+            datetime,
+            SimulationDateTime::from_components(-1, 1, 0, 0, 0, 0, 0)
+        );
+
+        let (datetime, timestamp) = SimulationDateTime::builder()
+            .year(i64::MAX)
+            .second(u64::MAX)
+            .build();
+        assert_eq!(
+            datetime,
+            SimulationDateTime::from_signed_epoch_seconds(i64::MAX)
+        );
+        assert_eq!(timestamp.epoch_seconds(), i64::MAX);
+
+        let (_, timestamp) = SimulationDateTime::builder()
+            .year(i64::MIN)
+            .second(u64::MAX)
+            .build();
+        assert_eq!(timestamp, SimulationTimestamp::MIN);
+
+        let (_, timestamp) = SimulationDateTime::builder()
+            .year(-92_233_720_369)
+            .month(9)
+            .build();
+        assert_eq!(timestamp.epoch_seconds(), -9_223_372_036_810_000_000);
+    }
+
+    #[test]
+    fn datetime_era_test() {
+        let datetime = SimulationDateTime::from_components(1_372, 4, 0, 0, 0, 0, 0);
+        assert_eq!(datetime.millennium(), 1);
+        assert_eq!(datetime.century(), 3);
+        assert_eq!(datetime.decade(), 7);
+        assert_eq!(datetime.year_of_decade(), 2);
+        assert_eq!(
+            datetime.to_era_string(),
+            "Millennium 1, Century 3, Decade 7, Year 2"
+        );
+
+        let before_epoch = SimulationDateTime::from_signed_epoch_seconds(-1);
+        assert_eq!(before_epoch.millennium(), -1);
+        assert_eq!(before_epoch.century(), 9);
+        assert_eq!(before_epoch.decade(), 9);
+        assert_eq!(before_epoch.year_of_decade(), 9);
+        assert_eq!(
+            SimulationDateTime::from(SimulationTimestamp::from_components(0, 0, 0, 0, 0, 0, 0))
+                .millennium(),
+            0
+        );
+    }
+
+    #[test]
+    fn datetime_subsecond_test() {
+        let datetime = SimulationDateTime::from(Duration::from_millis(1_234_567_250));
+        assert_eq!(datetime.second, 67);
+        assert_eq!(datetime.nanosecond, 250_000_000);
+        assert_eq!(datetime.to_string(), "0-00-01-02@03:45:67.25");
+        assert_eq!(
+            format!("{:#}", datetime),
+            "year: 0\nmonth: 0\nweek: 1\nday: 2\nhour: 3\nminute: 45\nsecond: 67\nnanosecond: 250000000"
+        );
+        assert_eq!(
+            "0-00-01-02@03:45:67.25".parse::<SimulationDateTime>(),
+            Ok(SimulationDateTime::from(Duration::from_millis(
+                1_234_567_250
+            )))
+        );
+
+        let before_epoch = SimulationDateTime::from(
+            SimulationTimestamp::from_signed_epoch_seconds(-1) + Duration::from_millis(1),
+        );
+        assert_eq!(before_epoch.year, -1);
+        assert_eq!(before_epoch.nanosecond, 1_000_000);
+
+        let mut clock = SimulationClock::default();
+        clock.tick(Duration::from_micros(1_500_001));
+        assert_eq!(clock.current_datetime().nanosecond, 500_001_000);
+        assert_eq!(
+            clock
+                .current_datetime()
+                .saturating_add_duration(Duration::from_millis(500))
+                .to_string(),
+            "0-00-00-00@00:00:02.000001"
+        );
+
+        let json = serde_json::to_string(&datetime).unwrap();
+        assert!(json.ends_with(r#""second":67,"nanosecond":250000000}"#));
+        assert_eq!(
+            serde_json::from_str::<SimulationDateTime>(&json).unwrap(),
+            datetime
+        );
+        assert!(serde_json::from_str::<SimulationDateTime>(
+            r#"{"year":0,"month":0,"week":0,"day":0,"hour":0,"minute":0,"second":0,"nanosecond":1000000000}"#
+        )
+        .is_err());
+        assert_eq!(
+            SimulationTimestamp::from(datetime),
+            SimulationTimestamp::from(Duration::from_millis(1_234_567_250))
+        );
+    }
+
+    #[test]
+    fn timestamp_components_test() {
+        for timestamp in [
+            SimulationTimestamp::from_components(12, 3, 4, 5, 6, 7, 8),
+            SimulationTimestamp::from_components(0, 9, 9, 9, 9, 99, 99),
+            SimulationTimestamp::from_signed_epoch_seconds(-1),
+            SimulationTimestamp::from_signed_epoch_seconds(i64::MIN),
+            SimulationTimestamp::from_signed_epoch_seconds(i64::MAX),
+        ] {
+            let datetime = SimulationDateTime::from(timestamp);
+            assert_eq!(
+                (
+                    timestamp.year(),
+                    timestamp.month(),
+                    timestamp.week(),
+                    timestamp.day(),
+                    timestamp.hour(),
+                    timestamp.minute(),
+                    timestamp.second(),
+                ),
+                datetime.as_tuple()
+            );
+        }
+        let timestamp = SimulationTimestamp::from_components(12, 3, 4, 5, 6, 7, 8);
+        assert_eq!(timestamp.day_of_metric_year(), 345);
+        assert_eq!(timestamp.second_of_metric_day(), 60_708);
+        let before_epoch = SimulationTimestamp::from_signed_epoch_seconds(-1);
+        assert_eq!(before_epoch.day_of_metric_year(), 999);
+        assert_eq!(before_epoch.second_of_metric_day(), 99_999);
+    }
+
+    #[test]
+    fn calendar_position_test() {
+        for timestamp in [
+            SimulationTimestamp::from_components(12, 3, 4, 5, 6, 7, 8),
+            SimulationTimestamp::from_components(0, 9, 9, 9, 9, 99, 99),
+            SimulationTimestamp::from_signed_epoch_seconds(-123_456_789),
+        ] {
+            let datetime = SimulationDateTime::from(timestamp);
+            assert_eq!(
+                datetime.day_of_metric_year(),
+                timestamp.day_of_metric_year()
+            );
+            assert_eq!(
+                datetime.week_of_metric_year(),
+                timestamp.week_of_metric_year()
+            );
+            assert_eq!(
+                datetime.second_of_metric_day(),
+                timestamp.second_of_metric_day()
+            );
+        }
+        let datetime = SimulationDateTime::from_components(12, 3, 4, 5, 6, 7, 8);
+        assert_eq!(datetime.day_of_metric_year(), 345);
+        assert_eq!(datetime.week_of_metric_year(), 34);
+        assert_eq!(datetime.second_of_metric_day(), 60_708);
+        let last = SimulationDateTime::from_components(0, 9, 9, 9, 9, 99, 99);
+        assert_eq!(last.day_of_metric_year(), 999);
+        assert_eq!(last.week_of_metric_year(), 99);
+        assert_eq!(last.second_of_metric_day(), 99_999);
+        let unnormalized = SimulationDateTime::from_components(0, 25, 6, 0, 0, 0, 0);
+        assert_eq!(unnormalized.week_of_metric_year(), 256);
+        let widest = SimulationDateTime::from_components(0, 255, 255, 255, 255, 255, 255);
+        assert_eq!(widest.day_of_metric_year(), 28_305);
+        assert_eq!(widest.week_of_metric_year(), 2_805);
+    }
+
+    #[test]
+    fn timestamp_bounds_test() {
+        assert_eq!(SimulationTimestamp::EPOCH.epoch_seconds(), 0);
+        assert_eq!(SimulationTimestamp::MIN.epoch_seconds(), i64::MIN);
+        assert_eq!(SimulationTimestamp::MAX.epoch_seconds(), i64::MAX);
+        assert_eq!(
+            SimulationClock::default().current_timestamp(),
+            SimulationTimestamp::EPOCH
+        );
+        assert_eq!(
+            SimulationTimestamp::from_epoch_seconds(u64::MAX),
+            SimulationTimestamp::MAX
+        );
+        assert_eq!(
+            SimulationTimestamp::from(Duration::MAX),
+            SimulationTimestamp::MAX
+        );
+        assert_eq!(
+            SimulationTimestamp::MAX.saturating_add(Duration::from_nanos(1)),
+            SimulationTimestamp::MAX
+        );
+        assert_eq!(
+            SimulationTimestamp::MAX.checked_add(Duration::from_nanos(1)),
+            None
+        );
+        assert_eq!(
+            SimulationTimestamp::MIN.saturating_sub(Duration::from_nanos(1)),
+            SimulationTimestamp::MIN
+        );
+        assert_eq!(
+            SimulationTimestamp::MIN.checked_sub(Duration::from_nanos(1)),
+            None
+        );
+        assert!(SimulationTimestamp::MIN < SimulationTimestamp::EPOCH);
+        assert!(SimulationTimestamp::EPOCH < SimulationTimestamp::MAX);
+    }
+
+    #[test]
+    fn const_config_test() {
+        const START: SimulationTimestamp = SimulationTimestamp::from_signed_epoch_seconds(-500);
+        const TURN: SimulationDuration = SimulationDuration::from_units(2, MetricScale::Hour);
+        static SCENARIO: SimulationClockConfig = SimulationClockConfig::new(START)
+            .with_mode(ClockMode::TurnBased)
+            .with_speed(4.0)
+            .with_turn_duration(TURN.as_duration());
+        const { assert!(START.is_before_epoch()) };
+
+        let mut clock = SimulationClock::from_config(&SCENARIO);
+        assert_eq!(clock.current_timestamp(), START);
+        assert_eq!(clock.mode(), ClockMode::TurnBased);
+        assert_eq!(clock.clock_speed(), 4.0);
+        assert_eq!(clock.turn_duration_metric(), TURN);
+        assert_eq!(clock.turn_time_remaining(), Duration::from_secs(20_000));
+        clock.tick(Duration::from_secs(5_000));
+        assert!(clock.turn_complete());
+
+        assert_eq!(
+            SimulationClock::from(SimulationClockConfig::default()),
+            SimulationClock::default()
+        );
+        let strict = SimulationClock::from_config(
+            &SimulationClockConfig::default().with_mode(ClockMode::StrictTurn),
+        );
+        assert_eq!(strict.mode(), ClockMode::StrictTurn);
+    }
+
+    #[test]
+    fn timestamp_iter_to_test() {
+        let start = SimulationTimestamp::from_components(1, 2, 3, 4, 0, 0, 0);
+        let end = SimulationTimestamp::from_components(1, 2, 4, 1, 0, 0, 0);
+        let days: Vec<SimulationDateTime> = start
+            .iter_to(end, MetricUnit::Day)
+            .map(SimulationDateTime::from)
+            .collect();
+        assert_eq!(days.len(), 7);
+        assert_eq!(
+            days[0],
+            SimulationDateTime::from_components(1, 2, 3, 4, 0, 0, 0)
+        );
+        assert_eq!(
+            days[6],
+            SimulationDateTime::from_components(1, 2, 4, 0, 0, 0, 0)
+        );
+
+        let hours: Vec<i64> = SimulationTimestamp::from_signed_epoch_seconds(-25_000)
+            .iter_to(
+                SimulationTimestamp::from_epoch_seconds(5_000),
+                MetricUnit::Hour,
+            )
+            .map(|hour| hour.epoch_seconds())
+            .collect();
+        assert_eq!(hours, [-20_000, -10_000, 0]);
+
+        assert_eq!(start.iter_to(start, MetricUnit::Second).count(), 0);
+        assert_eq!(end.iter_to(start, MetricUnit::Second).count(), 0);
+        assert_eq!(start.iter_to(end, Duration::ZERO).count(), 0);
+        assert_eq!(
+            SimulationTimestamp::MAX
+                .floor_to(MetricUnit::Second)
+                .iter_to(SimulationTimestamp::MAX, MetricUnit::Second)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn time_until_since_test() {
+        let mut clock = SimulationClock::from_seconds(1_000);
+        clock.set_clock_speed(1.0);
+        let deadline = SimulationTimestamp::from_epoch_seconds(1_500);
+        let founding = SimulationTimestamp::from_signed_epoch_seconds(-200);
+        assert_eq!(
+            clock.time_until(deadline),
+            SimulationDuration::from_metric_seconds(500)
+        );
+        assert_eq!(clock.time_since(deadline), SimulationDuration::default());
+        assert_eq!(
+            clock.time_since(founding),
+            SimulationDuration::from_metric_seconds(1_200)
+        );
+        assert_eq!(clock.time_until(founding), SimulationDuration::default());
+        assert!(!clock.is_past(deadline));
+        assert!(clock.is_past(founding));
+
+        clock.tick(Duration::from_secs(500));
+        assert!(clock.is_past(deadline));
+        assert_eq!(clock.time_until(deadline), SimulationDuration::default());
+        assert_eq!(clock.time_since(deadline), SimulationDuration::default());
+    }
+
+    #[test]
+    fn elapsed_since_test() {
+        let founding = SimulationDateTime::from_components(-2, 8, 5, 0, 0, 0, 0);
+        let today = SimulationDateTime::from_components(40, 1, 3, 7, 2, 50, 10);
+        assert_eq!(
+            today.elapsed_since(&founding),
+            MetricElapsed {
+                years: 41,
+                months: 2,
+                weeks: 8,
+                days: 7,
+                hours: 2,
+                minutes: 50,
+                seconds: 10,
+                nanoseconds: 0,
+            }
+        );
+        assert_eq!(founding.elapsed_since(&today), MetricElapsed::default());
+        assert_eq!(
+            SimulationTimestamp::from(today).elapsed_since(&SimulationTimestamp::from(founding)),
+            SimulationTimestamp::from_components(41, 2, 8, 7, 2, 50, 10)
+                .duration_since(&SimulationTimestamp::EPOCH)
+                .components()
+        );
+    }
+
+    #[test]
+    fn fractional_units_test() {
+        let timestamp = SimulationTimestamp::from_components(12, 5, 0, 0, 0, 0, 0);
+        assert_eq!(timestamp.as_metric_years_f64(), 12.5);
+        assert_eq!(timestamp.as_metric_days_f64(), 12_500.0);
+        assert_eq!(timestamp.as_metric_hours_f64(), 125_000.0);
+        assert_eq!(timestamp.as_metric_units_f64(MetricUnit::Century), 0.125);
+        assert_eq!(SimulationTimestamp::from_metric_years_f64(12.5), timestamp);
+        assert_eq!(
+            SimulationTimestamp::from_metric_days_f64(12_500.0),
+            timestamp
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_days_f64(-0.25),
+            SimulationTimestamp::from_signed_epoch_seconds(-25_000)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_units_f64(1.5, MetricUnit::Second),
+            SimulationTimestamp::from(Duration::from_millis(1_500))
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_days_f64(f64::NAN),
+            SimulationTimestamp::EPOCH
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_years_f64(f64::INFINITY),
+            SimulationTimestamp::MAX
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_years_f64(-1e300),
+            SimulationTimestamp::MIN
+        );
+    }
+
+    #[test]
+    fn epoch_nanos_test() {
+        for timestamp in [
+            SimulationTimestamp::MIN,
+            SimulationTimestamp::from_signed_epoch_seconds(-1) + Duration::from_nanos(7),
+            SimulationTimestamp::EPOCH,
+            SimulationTimestamp::from(Duration::new(123_456, 789)),
+            SimulationTimestamp::MAX,
+        ] {
+            assert_eq!(
+                SimulationTimestamp::from_epoch_nanos(timestamp.as_epoch_nanos()),
+                timestamp
+            );
+            if let Some(nanos) = timestamp.as_epoch_nanos_u128() {
+                assert_eq!(SimulationTimestamp::from_epoch_nanos_u128(nanos), timestamp);
+            }
+        }
+        let timestamp = SimulationTimestamp::from(Duration::new(123_456, 789));
+        assert_eq!(timestamp.as_epoch_nanos(), 123_456_000_000_789);
+        assert_eq!(timestamp.as_epoch_nanos_u128(), Some(123_456_000_000_789));
+        assert_eq!(timestamp.as_epoch_seconds_u64(), Some(123_456));
+        let before_epoch = SimulationTimestamp::from_signed_epoch_seconds(-1);
+        assert_eq!(before_epoch.as_epoch_nanos(), -1_000_000_000);
+        assert_eq!(before_epoch.as_epoch_nanos_u128(), None);
+        assert_eq!(before_epoch.as_epoch_seconds_u64(), None);
+        assert_eq!(
+            SimulationTimestamp::MAX.as_epoch_seconds_u64(),
+            Some(i64::MAX as u64)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_epoch_nanos_u128(u128::MAX),
+            SimulationTimestamp::MAX
+        );
+        assert_eq!(
+            SimulationTimestamp::from_epoch_nanos(i128::MIN),
+            SimulationTimestamp::MIN
+        );
+    }
+
+    #[test]
+    fn fractional_constructors_test() {
+        assert_eq!(
+            SimulationTimestamp::from_metric_years(12.5),
+            SimulationTimestamp::from_components(12, 5, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_millennia(0.5),
+            SimulationTimestamp::from_components(500, 0, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_centuries(-0.25),
+            SimulationTimestamp::from_components(-25, 0, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_decades(1.5),
+            SimulationTimestamp::from_components(15, 0, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_months(2.5),
+            SimulationTimestamp::from_components(0, 2, 5, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_weeks(0.3),
+            SimulationTimestamp::from_components(0, 0, 0, 3, 0, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_days(1.5),
+            SimulationTimestamp::from_components(0, 0, 0, 1, 5, 0, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_hours(0.5),
+            SimulationTimestamp::from_components(0, 0, 0, 0, 0, 50, 0)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_minutes(1.25),
+            SimulationTimestamp::from_components(0, 0, 0, 0, 0, 1, 25)
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_seconds(2.0000000015).as_epoch_nanos(),
+            2_000_000_002
+        );
+        assert_eq!(
+            SimulationTimestamp::from_metric_seconds(-2.0000000015).as_epoch_nanos(),
+            -2_000_000_002
+        );
+    }
+
+    #[test]
+    fn quantize_turns_test() {
+        let mut clock = SimulationClock::default();
+        clock.enable_strict_turn_mode();
+        clock.advance_turn();
+        clock.advance_turn();
+        assert_eq!(clock.turn_number(), 2);
+        assert_eq!(clock.current_epoch_seconds(), 12.0);
+
+        let at = |seconds: u64| SimulationTimestamp::from_epoch_seconds(seconds);
+        assert_eq!(clock.quantize(at(14)), at(12));
+        assert_eq!(clock.quantize(at(15)), at(18));
+        assert_eq!(clock.quantize(at(40)), at(42));
+        assert_eq!(clock.quantize(at(2)), at(0));
+        assert_eq!(
+            clock.quantize(SimulationTimestamp::from_signed_epoch_seconds(-4)),
+            SimulationTimestamp::from_signed_epoch_seconds(-6)
+        );
+
+        assert_eq!(clock.turn_index_of(at(12)), 2);
+        assert_eq!(clock.turn_index_of(at(17)), 2);
+        assert_eq!(clock.turn_index_of(at(18)), 3);
+        assert_eq!(clock.turn_index_of(at(0)), 0);
+        assert_eq!(
+            clock.turn_index_of(SimulationTimestamp::from_signed_epoch_seconds(-1)),
+            -1
+        );
+        assert_eq!(clock.turn_index_of(clock.quantize(at(40))), 7);
+    }
+
+    #[test]
+    fn timestamp_from_str_test() {
+        for timestamp in [
+            SimulationTimestamp::EPOCH,
+            SimulationTimestamp::from_epoch_seconds(200_300_000),
+            SimulationTimestamp::from_signed_epoch_seconds(-13) + Duration::from_millis(500),
+            SimulationTimestamp::from(Duration::new(7, 1)),
+            SimulationTimestamp::MIN,
+            SimulationTimestamp::MAX,
+        ] {
+            assert_eq!(timestamp.to_string().parse(), Ok(timestamp));
+        }
+        assert_eq!(
+            "12-03-04-05@06:07:08".parse(),
+            Ok(SimulationTimestamp::from_components(12, 3, 4, 5, 6, 7, 8))
+        );
+        assert_eq!(
+            "-0001-09-09-09@09:99:99.5".parse(),
+            Ok(SimulationTimestamp::from_signed_epoch_seconds(-1) + Duration::from_millis(500))
+        );
+        assert_eq!(
+            "0.25".parse(),
+            Ok(SimulationTimestamp::from(Duration::from_millis(250)))
+        );
+        for input in [
+            "",
+            "-",
+            "1.",
+            ".5",
+            "+1",
+            "1.2345678901",
+            "1e3",
+            "12-03-04-05",
+        ] {
+            assert_eq!(
+                input.parse::<SimulationTimestamp>(),
+                Err(MetricError::Parse(input.to_string()))
+            );
+        }
+        assert_eq!(
+            "9223372036854775808".parse::<SimulationTimestamp>(),
+            Err(MetricError::Overflow)
+        );
+        assert_eq!(
+            "92233720369-00-00-00@00:00:00".parse::<SimulationTimestamp>(),
+            Err(MetricError::Overflow)
+        );
+        assert!(matches!(
+            "0-10-00-00@00:00:00".parse::<SimulationTimestamp>(),
+            Err(MetricError::OutOfRange {
+                component: "month",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn earliest_year_test() {
+        let earliest = SimulationDateTime::from(SimulationTimestamp::MIN);
+        assert_eq!(earliest.year, -92_233_720_369);
+        assert_eq!(earliest.to_epoch_seconds(), i64::MIN);
+        assert_eq!(
+            SimulationTimestamp::try_from_datetime(&earliest),
+            Ok(SimulationTimestamp::MIN)
+        );
+        assert_eq!(
+            SimulationTimestamp::try_from_components(-92_233_720_369, 0, 0, 0, 0, 0, 0),
+            Err(MetricError::Overflow)
+        );
+        assert_eq!(
+            SimulationDateTime::from_components(-92_233_720_369, 0, 0, 0, 0, 0, 0)
+                .to_epoch_seconds(),
+            i64::MIN
+        );
+    }
 }
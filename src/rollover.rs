@@ -0,0 +1,151 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::{MetricScale, SimulationClock};
+use std::sync::{Mutex, PoisonError};
+
+/// Set of Metric Units whose boundary was crossed
+///
+/// Crossing the boundary of a unit also crosses the boundary of every finer unit, so a Year
+/// rollover always carries the Month, Week, Day, Hour, Minute, and Second bits as well.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct RolloverFlags(u16);
+
+impl RolloverFlags {
+    /// No Rollovers
+    pub const NONE: RolloverFlags = RolloverFlags(0);
+    /// Metric Second Rollover
+    pub const SECOND: RolloverFlags = RolloverFlags(1 << 0);
+    /// Metric Minute Rollover
+    pub const MINUTE: RolloverFlags = RolloverFlags(1 << 1);
+    /// Metric Hour Rollover
+    pub const HOUR: RolloverFlags = RolloverFlags(1 << 2);
+    /// Metric Day Rollover
+    pub const DAY: RolloverFlags = RolloverFlags(1 << 3);
+    /// Metric Week Rollover
+    pub const WEEK: RolloverFlags = RolloverFlags(1 << 4);
+    /// Metric Month Rollover
+    pub const MONTH: RolloverFlags = RolloverFlags(1 << 5);
+    /// Metric Year Rollover
+    pub const YEAR: RolloverFlags = RolloverFlags(1 << 6);
+    /// Metric Decade Rollover
+    pub const DECADE: RolloverFlags = RolloverFlags(1 << 7);
+    /// Metric Century Rollover
+    pub const CENTURY: RolloverFlags = RolloverFlags(1 << 8);
+    /// Metric Millennium Rollover
+    pub const MILLENNIUM: RolloverFlags = RolloverFlags(1 << 9);
+
+    /// Rollovers that occur moving from `before` to `after` epoch seconds, which are negative
+    /// before the epoch.
+    pub fn between(before: i64, after: i64) -> RolloverFlags {
+        MetricScale::all()
+            .iter()
+            .copied()
+            .filter(|scale| {
+                let unit = scale.seconds_per_unit() as i64;
+                before.div_euclid(unit) != after.div_euclid(unit)
+            })
+            .fold(RolloverFlags::NONE, |flags, scale| flags | scale.into())
+    }
+    /// Raw bit representation
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+    /// Whether no rollover bits are set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+    /// Whether every bit in `other` is also set in `self`.
+    pub fn contains(&self, other: RolloverFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// Set every bit in `other`.
+    pub fn insert(&mut self, other: RolloverFlags) {
+        self.0 |= other.0;
+    }
+}
+
+impl From<MetricScale> for RolloverFlags {
+    fn from(scale: MetricScale) -> Self {
+        match scale {
+            MetricScale::Millennium => RolloverFlags::MILLENNIUM,
+            MetricScale::Century => RolloverFlags::CENTURY,
+            MetricScale::Decade => RolloverFlags::DECADE,
+            MetricScale::Year => RolloverFlags::YEAR,
+            MetricScale::Month => RolloverFlags::MONTH,
+            MetricScale::Week => RolloverFlags::WEEK,
+            MetricScale::Day => RolloverFlags::DAY,
+            MetricScale::Hour => RolloverFlags::HOUR,
+            MetricScale::Minute => RolloverFlags::MINUTE,
+            MetricScale::Second => RolloverFlags::SECOND,
+        }
+    }
+}
+
+impl std::ops::BitOr for RolloverFlags {
+    type Output = RolloverFlags;
+    fn bitor(self, rhs: RolloverFlags) -> RolloverFlags {
+        RolloverFlags(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for RolloverFlags {
+    fn bitor_assign(&mut self, rhs: RolloverFlags) {
+        self.insert(rhs);
+    }
+}
+
+impl std::ops::BitAnd for RolloverFlags {
+    type Output = RolloverFlags;
+    fn bitand(self, rhs: RolloverFlags) -> RolloverFlags {
+        RolloverFlags(self.0 & rhs.0)
+    }
+}
+
+type RolloverCallback = Box<dyn FnMut(&SimulationClock) + Send>;
+
+/// Callbacks registered through `SimulationClock::on_rollover`
+///
+/// Callbacks are never serialized and are not carried over when the clock is cloned. The
+/// `Mutex` is only there to keep the clock `Sync`; it is always accessed through `&mut self`.
+#[derive(Default)]
+pub(crate) struct RolloverCallbacks(Mutex<Vec<(MetricScale, RolloverCallback)>>);
+
+impl RolloverCallbacks {
+    pub(crate) fn push(&mut self, scale: MetricScale, callback: RolloverCallback) {
+        self.callbacks().push((scale, callback));
+    }
+    pub(crate) fn is_empty(&mut self) -> bool {
+        self.callbacks().is_empty()
+    }
+    /// Remove every callback so they can be invoked while the clock is borrowed.
+    pub(crate) fn take(&mut self) -> Vec<(MetricScale, RolloverCallback)> {
+        std::mem::take(self.callbacks())
+    }
+    /// Put back callbacks removed with `take`.
+    pub(crate) fn restore(&mut self, callbacks: Vec<(MetricScale, RolloverCallback)>) {
+        *self.callbacks() = callbacks;
+    }
+    fn callbacks(&mut self) -> &mut Vec<(MetricScale, RolloverCallback)> {
+        self.0.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for RolloverCallbacks {
+    fn clone(&self) -> Self {
+        RolloverCallbacks::default()
+    }
+}
@@ -0,0 +1,137 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::{
+    SECONDS_PER_METRIC_CENTURY, SECONDS_PER_METRIC_DAY, SECONDS_PER_METRIC_DECADE,
+    SECONDS_PER_METRIC_HOUR, SECONDS_PER_METRIC_MILLENNIUM, SECONDS_PER_METRIC_MINUTE,
+    SECONDS_PER_METRIC_MONTH, SECONDS_PER_METRIC_WEEK, SECONDS_PER_METRIC_YEAR,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Metric Time Unit
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum MetricScale {
+    /// 100,000,000,000 Seconds
+    Millennium,
+    /// 10,000,000,000 Seconds
+    Century,
+    /// 1,000,000,000 Seconds
+    Decade,
+    /// 100,000,000 Seconds
+    Year,
+    /// 10,000,000 Seconds
+    Month,
+    /// 1,000,000 Seconds
+    Week,
+    /// 100,000 Seconds
+    Day,
+    /// 10,000 Seconds
+    Hour,
+    /// 100 Seconds
+    Minute,
+    /// 1 Second
+    Second,
+}
+
+impl MetricScale {
+    /// Every Metric Unit from largest to smallest.
+    pub fn all() -> &'static [MetricScale] {
+        &[
+            MetricScale::Millennium,
+            MetricScale::Century,
+            MetricScale::Decade,
+            MetricScale::Year,
+            MetricScale::Month,
+            MetricScale::Week,
+            MetricScale::Day,
+            MetricScale::Hour,
+            MetricScale::Minute,
+            MetricScale::Second,
+        ]
+    }
+    /// Display name of this Metric Unit.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetricScale::Millennium => "Metric Millennium",
+            MetricScale::Century => "Metric Century",
+            MetricScale::Decade => "Metric Decade",
+            MetricScale::Year => "Metric Year",
+            MetricScale::Month => "Metric Month",
+            MetricScale::Week => "Metric Week",
+            MetricScale::Day => "Metric Day",
+            MetricScale::Hour => "Metric Hour",
+            MetricScale::Minute => "Metric Minute",
+            MetricScale::Second => "Metric Second",
+        }
+    }
+    /// Seconds in one of this Metric Unit.
+    pub const fn seconds_per_unit(&self) -> u64 {
+        match self {
+            MetricScale::Millennium => SECONDS_PER_METRIC_MILLENNIUM,
+            MetricScale::Century => SECONDS_PER_METRIC_CENTURY,
+            MetricScale::Decade => SECONDS_PER_METRIC_DECADE,
+            MetricScale::Year => SECONDS_PER_METRIC_YEAR,
+            MetricScale::Month => SECONDS_PER_METRIC_MONTH,
+            MetricScale::Week => SECONDS_PER_METRIC_WEEK,
+            MetricScale::Day => SECONDS_PER_METRIC_DAY,
+            MetricScale::Hour => SECONDS_PER_METRIC_HOUR,
+            MetricScale::Minute => SECONDS_PER_METRIC_MINUTE,
+            MetricScale::Second => 1,
+        }
+    }
+}
+
+/// Metric Unit used to pick a rounding boundary, such as in
+/// [`SimulationTimestamp::floor_to`](crate::SimulationTimestamp::floor_to)
+///
+/// This is the same type as [`MetricScale`].
+pub type MetricUnit = MetricScale;
+
+/// One of this Metric Unit.
+impl From<MetricScale> for Duration {
+    fn from(scale: MetricScale) -> Self {
+        Duration::from_secs(scale.seconds_per_unit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MetricScale;
+
+    #[test]
+    fn all_scales_test() {
+        let table: Vec<(&str, u64)> = MetricScale::all()
+            .iter()
+            .map(|scale| (scale.name(), scale.seconds_per_unit()))
+            .collect();
+        assert_eq!(
+            table,
+            vec![
+                ("Metric Millennium", 100_000_000_000),
+                ("Metric Century", 10_000_000_000),
+                ("Metric Decade", 1_000_000_000),
+                ("Metric Year", 100_000_000),
+                ("Metric Month", 10_000_000),
+                ("Metric Week", 1_000_000),
+                ("Metric Day", 100_000),
+                ("Metric Hour", 10_000),
+                ("Metric Minute", 100),
+                ("Metric Second", 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::{SimulationClock, SimulationTimestamp};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+/// Source of the current Simulation Time
+///
+/// Systems that only read the time should depend on `&dyn ClockSource` rather than a concrete
+/// [`SimulationClock`] so a [`MockClockSource`] can be injected under test.
+///
+/// ```rust
+/// use metriclock::{ClockSource, MockClockSource, SimulationDateTime, SimulationTimestamp};
+///
+/// fn is_night(source: &dyn ClockSource) -> bool {
+///     SimulationDateTime::from(source.now()).hour >= 5
+/// }
+///
+/// let source = MockClockSource::new(SimulationTimestamp::from_epoch_seconds(75_000));
+/// assert!(is_night(&source));
+/// ```
+pub trait ClockSource {
+    /// Current Simulation Timestamp
+    fn now(&self) -> SimulationTimestamp;
+}
+
+impl ClockSource for SimulationClock {
+    fn now(&self) -> SimulationTimestamp {
+        self.current_timestamp()
+    }
+}
+
+/// Lock-free handle reading the whole epoch seconds published by a [`SimulationClock`]
+///
+/// Obtained from [`SimulationClock::time_reader`], it may be sent to another thread to observe
+/// the clock while it is ticked elsewhere.
+#[derive(Clone, Debug)]
+pub struct ClockReader {
+    epoch_seconds: Arc<AtomicI64>,
+}
+
+impl ClockReader {
+    pub(crate) fn new(epoch_seconds: Arc<AtomicI64>) -> ClockReader {
+        ClockReader { epoch_seconds }
+    }
+}
+
+impl ClockSource for ClockReader {
+    fn now(&self) -> SimulationTimestamp {
+        SimulationTimestamp::from_signed_epoch_seconds(self.epoch_seconds.load(Ordering::Acquire))
+    }
+}
+
+/// Atomically published epoch seconds shared with [`ClockReader`] handles
+///
+/// Cloning creates an independent cell so a cloned clock does not publish into the readers of
+/// the original.
+#[derive(Debug, Default)]
+pub(crate) struct AtomicEpoch(Arc<AtomicI64>);
+
+impl AtomicEpoch {
+    pub(crate) fn load(&self) -> i64 {
+        self.0.load(Ordering::Acquire)
+    }
+    pub(crate) fn store(&self, epoch_seconds: i64) {
+        self.0.store(epoch_seconds, Ordering::Release);
+    }
+    pub(crate) fn reader(&self) -> ClockReader {
+        ClockReader::new(self.0.clone())
+    }
+}
+
+impl Clone for AtomicEpoch {
+    fn clone(&self) -> Self {
+        AtomicEpoch(Arc::new(AtomicI64::new(self.load())))
+    }
+}
+
+/// Clock Source whose time is set explicitly
+#[derive(Clone, Debug)]
+pub struct MockClockSource {
+    now: SimulationTimestamp,
+}
+
+impl MockClockSource {
+    /// Create a new Mock Clock Source reporting `now`.
+    pub fn new(now: SimulationTimestamp) -> MockClockSource {
+        MockClockSource { now }
+    }
+    /// Change the time reported by this Mock Clock Source.
+    pub fn set_now(&mut self, now: SimulationTimestamp) {
+        self.now = now;
+    }
+}
+
+impl Default for MockClockSource {
+    fn default() -> MockClockSource {
+        MockClockSource::new(SimulationTimestamp::EPOCH)
+    }
+}
+
+impl ClockSource for MockClockSource {
+    fn now(&self) -> SimulationTimestamp {
+        self.now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClockSource, MockClockSource};
+    use crate::{SimulationClock, SimulationDateTime, SimulationTimestamp};
+    use std::time::Duration;
+
+    fn current_hour(source: &dyn ClockSource) -> u8 {
+        SimulationDateTime::from(source.now()).hour
+    }
+
+    #[test]
+    fn mock_source_test() {
+        let mut source = MockClockSource::default();
+        assert_eq!(current_hour(&source), 0);
+        source.set_now(SimulationTimestamp::from_epoch_seconds(30_000));
+        assert_eq!(current_hour(&source), 3);
+
+        let mut clock = SimulationClock::default();
+        clock.tick(Duration::from_secs(70_000));
+        assert_eq!(current_hour(&clock), 7);
+    }
+}
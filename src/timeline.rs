@@ -0,0 +1,60 @@
+//
+// Copyright 2020-2022 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::SimulationTimestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Scripted Clock Speed changes keyed by Simulation Time
+///
+/// Once attached to a [`SimulationClock`](crate::SimulationClock), each keyframe's speed is
+/// applied by the first `tick` that reaches its time. Keyframes are applied in time order and
+/// only once.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Timeline {
+    /// Pending keyframes in chronological order
+    keyframes: VecDeque<(SimulationTimestamp, f64)>,
+}
+
+impl Timeline {
+    /// Create an empty Timeline.
+    pub fn new() -> Timeline {
+        Timeline::default()
+    }
+    /// Schedule a change to `speed` once Simulation Time reaches `at`.
+    ///
+    /// Keyframes sharing a time are applied in the order they were added.
+    pub fn add_speed_change(&mut self, at: SimulationTimestamp, speed: f64) {
+        let index = self.keyframes.partition_point(|(time, _)| *time <= at);
+        self.keyframes.insert(index, (at, speed));
+    }
+    /// Number of keyframes not yet applied.
+    pub fn len(&self) -> usize {
+        self.keyframes.len()
+    }
+    /// Whether every keyframe has been applied.
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+    /// Remove every keyframe due at `now`, returning the speed of the latest one.
+    pub(crate) fn take_due(&mut self, now: &SimulationTimestamp) -> Option<f64> {
+        let mut speed = None;
+        while self.keyframes.front().is_some_and(|(time, _)| time <= now) {
+            speed = self.keyframes.pop_front().map(|(_, speed)| speed);
+        }
+        speed
+    }
+}